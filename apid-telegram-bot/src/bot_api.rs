@@ -0,0 +1,91 @@
+use std::{fmt, path::Path};
+
+use apid::{Api, Call, HttpResponse};
+
+use crate::{types::ApiResponse, Error, FileLocation};
+
+/// The base URL of the cloud Bot API server.
+pub const DEFAULT_BASE_URL: &str = "https://api.telegram.org";
+
+/// The Bot API server a bot sends its calls to.
+///
+/// Requests are built with [`Api::request`] and sent by any HTTP library,
+/// then the responses are decoded with [`Api::decode`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct BotApi {
+    /// The base URL of the Bot API server, e.g. [`DEFAULT_BASE_URL`] or the URL of a local Bot API server
+    pub base_url: String,
+
+    /// The authentication token of the bot
+    pub token: String,
+}
+
+impl BotApi {
+    /// Creates a [`BotApi`] for the bot with the given token on the cloud Bot API server.
+    pub fn new(token: impl Into<String>) -> Self {
+        BotApi::with_base_url(DEFAULT_BASE_URL, token)
+    }
+
+    /// Creates a [`BotApi`] for the bot with the given token on the Bot API server at `base_url`.
+    pub fn with_base_url(base_url: impl Into<String>, token: impl Into<String>) -> Self {
+        BotApi {
+            base_url: base_url.into(),
+            token: token.into(),
+        }
+    }
+
+    /// The URL to download the file at `file_path` from,
+    /// i.e. `<base_url>/file/bot<token>/<file_path>`.
+    pub fn file_url(&self, file_path: &str) -> String {
+        format!(
+            "{}/file/bot{}/{}",
+            self.base_url.trim_end_matches('/'),
+            self.token,
+            file_path.trim_start_matches('/')
+        )
+    }
+
+    /// Where the file at `file_path`, as returned by [`GetFile`](crate::calls::GetFile), can be read from.
+    ///
+    /// A local Bot API server returns the absolute path of the file on its machine,
+    /// which is read directly instead of downloaded.
+    /// POSIX absolute paths are recognized on every platform, since the server may run on another OS than the bot,
+    /// along with absolute paths of the platform the bot runs on, e.g. `C:\` on Windows.
+    pub fn file_location(&self, file_path: &str) -> FileLocation {
+        if file_path.starts_with('/') || Path::new(file_path).is_absolute() {
+            FileLocation::Local(file_path.into())
+        } else {
+            FileLocation::Url(self.file_url(file_path))
+        }
+    }
+}
+
+impl fmt::Debug for BotApi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BotApi")
+            .field("base_url", &self.base_url)
+            .field("token", &"<redacted>")
+            .finish()
+    }
+}
+
+impl Api for BotApi {
+    type Error = Error;
+
+    fn url(&self, name: &str) -> String {
+        format!(
+            "{}/bot{}/{}",
+            self.base_url.trim_end_matches('/'),
+            self.token,
+            name
+        )
+    }
+
+    fn decode<C: Call>(&self, response: HttpResponse) -> Result<C::Response, Self::Error> {
+        match serde_json::from_slice::<ApiResponse<C::Response>>(&response.body) {
+            Ok(body) => Ok(body.into_result()?),
+            Err(_) if !(200..300).contains(&response.status) => Err(Error::Status(response.status)),
+            Err(error) => Err(error.into()),
+        }
+    }
+}
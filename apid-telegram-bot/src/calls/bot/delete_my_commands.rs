@@ -0,0 +1,25 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::BotCommandScope;
+
+/// Use this method to delete the list of the bot's commands for the given scope and user language.
+/// After deletion, [higher level commands](https://core.telegram.org/bots/api#determining-list-of-commands) will be shown to affected users.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeleteMyCommands {
+    /// Describes scope of users for which the commands are relevant.
+    /// Defaults to [`BotCommandScope::Default`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope>,
+
+    /// A two-letter ISO 639-1 language code.
+    /// If empty, commands will be applied to all users from the given scope, for whose language there are no dedicated commands
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+impl Call for DeleteMyCommands {
+    type Response = bool;
+    const NAME: &'static str = "deleteMyCommands";
+}
@@ -0,0 +1,19 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::MenuButton;
+
+/// Use this method to get the current value of the bot's menu button in a private chat, or the default menu button.
+/// Returns [`MenuButton`] on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GetChatMenuButton {
+    /// Unique identifier for the target private chat.
+    /// If not specified, default bot's menu button will be returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<i64>,
+}
+
+impl Call for GetChatMenuButton {
+    type Response = MenuButton;
+    const NAME: &'static str = "getChatMenuButton";
+}
@@ -0,0 +1,24 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{BotCommand, BotCommandScope};
+
+/// Use this method to get the current list of the bot's commands for the given scope and user language.
+/// Returns an Array of [`BotCommand`] objects.
+/// If commands aren't set, an empty list is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GetMyCommands {
+    /// Describes scope of users.
+    /// Defaults to [`BotCommandScope::Default`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope>,
+
+    /// A two-letter ISO 639-1 language code or an empty string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+impl Call for GetMyCommands {
+    type Response = Vec<BotCommand>;
+    const NAME: &'static str = "getMyCommands";
+}
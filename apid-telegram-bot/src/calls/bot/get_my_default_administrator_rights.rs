@@ -0,0 +1,19 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatAdministratorRights;
+
+/// Use this method to get the current default administrator rights of the bot.
+/// Returns [`ChatAdministratorRights`] on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GetMyDefaultAdministratorRights {
+    /// Pass *True* to get default administrator rights of the bot in channels.
+    /// Otherwise, default administrator rights of the bot for groups and supergroups will be returned.
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub for_channels: bool,
+}
+
+impl Call for GetMyDefaultAdministratorRights {
+    type Response = ChatAdministratorRights;
+    const NAME: &'static str = "getMyDefaultAdministratorRights";
+}
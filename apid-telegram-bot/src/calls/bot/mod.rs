@@ -0,0 +1,15 @@
+pub use delete_my_commands::*;
+pub use get_chat_menu_button::*;
+pub use get_my_commands::*;
+pub use get_my_default_administrator_rights::*;
+pub use set_chat_menu_button::*;
+pub use set_my_commands::*;
+pub use set_my_default_administrator_rights::*;
+
+mod delete_my_commands;
+mod get_chat_menu_button;
+mod get_my_commands;
+mod get_my_default_administrator_rights;
+mod set_chat_menu_button;
+mod set_my_commands;
+mod set_my_default_administrator_rights;
@@ -0,0 +1,24 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::MenuButton;
+
+/// Use this method to change the bot's menu button in a private chat, or the default menu button.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SetChatMenuButton {
+    /// Unique identifier for the target private chat.
+    /// If not specified, default bot's menu button will be changed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<i64>,
+
+    /// A JSON-serialized object for the bot's new menu button.
+    /// Defaults to [`MenuButton::Default`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub menu_button: Option<MenuButton>,
+}
+
+impl Call for SetChatMenuButton {
+    type Response = bool;
+    const NAME: &'static str = "setChatMenuButton";
+}
@@ -0,0 +1,29 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{BotCommand, BotCommandScope};
+
+/// Use this method to change the list of the bot's commands.
+/// See [https://core.telegram.org/bots#commands](https://core.telegram.org/bots#commands) for more details about bot commands.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SetMyCommands {
+    /// A list of bot commands to be set as the list of the bot's commands.
+    /// At most 100 commands can be specified.
+    pub commands: Vec<BotCommand>,
+
+    /// Describes scope of users for which the commands are relevant.
+    /// Defaults to [`BotCommandScope::Default`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope>,
+
+    /// A two-letter ISO 639-1 language code.
+    /// If empty, commands will be applied to all users from the given scope, for whose language there are no dedicated commands
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+impl Call for SetMyCommands {
+    type Response = bool;
+    const NAME: &'static str = "setMyCommands";
+}
@@ -0,0 +1,25 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatAdministratorRights;
+
+/// Use this method to change the default administrator rights requested by the bot when it's added as an administrator to groups or channels.
+/// These rights will be suggested to users, but they are free to modify the list before adding the bot.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SetMyDefaultAdministratorRights {
+    /// A JSON-serialized object describing new default administrator rights.
+    /// If not specified, the default administrator rights will be cleared.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rights: Option<ChatAdministratorRights>,
+
+    /// Pass *True* to change the default administrator rights of the bot in channels.
+    /// Otherwise, the default administrator rights of the bot for groups and supergroups will be changed.
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub for_channels: bool,
+}
+
+impl Call for SetMyDefaultAdministratorRights {
+    type Response = bool;
+    const NAME: &'static str = "setMyDefaultAdministratorRights";
+}
@@ -0,0 +1,34 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to ban a user in a group, a supergroup or a channel.
+/// In the case of supergroups and channels, the user will not be able to return to the chat on their own using invite links, etc., unless [unbanned](crate::calls::UnbanChatMember) first.
+/// The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct BanChatMember {
+    /// Unique identifier for the target group or username of the target supergroup or channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Unique identifier of the target user
+    pub user_id: i64,
+
+    /// Date when the user will be unbanned, unix time.
+    /// If user is banned for more than 366 days or less than 30 seconds from the current time they are considered to be banned forever.
+    /// Applied for supergroups and channels only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_date: Option<i32>,
+
+    /// Pass *True* to delete all messages from the chat for the user that is being removed.
+    /// If *False*, the user will be able to see messages in the group that were sent before the user was removed.
+    /// Always *True* for supergroups and channels.
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub revoke_messages: bool,
+}
+
+impl Call for BanChatMember {
+    type Response = bool;
+    const NAME: &'static str = "banChatMember";
+}
@@ -0,0 +1,22 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to ban a channel chat in a supergroup or a channel.
+/// Until the chat is [unbanned](crate::calls::UnbanChatSenderChat), the owner of the banned chat won't be able to send messages on behalf of **any of their channels**.
+/// The bot must be an administrator in the supergroup or channel for this to work and must have the appropriate administrator rights.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct BanChatSenderChat {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Unique identifier of the target sender chat
+    pub sender_chat_id: i64,
+}
+
+impl Call for BanChatSenderChat {
+    type Response = bool;
+    const NAME: &'static str = "banChatSenderChat";
+}
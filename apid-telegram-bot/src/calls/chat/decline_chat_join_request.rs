@@ -0,0 +1,21 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to decline a chat join request.
+/// The bot must be an administrator in the chat for this to work and must have the *can_invite_users* administrator right.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeclineChatJoinRequest {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Unique identifier of the target user
+    pub user_id: i64,
+}
+
+impl Call for DeclineChatJoinRequest {
+    type Response = bool;
+    const NAME: &'static str = "declineChatJoinRequest";
+}
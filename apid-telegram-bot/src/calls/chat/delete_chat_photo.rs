@@ -0,0 +1,19 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to delete a chat photo.
+/// Photos can't be changed for private chats.
+/// The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeleteChatPhoto {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+}
+
+impl Call for DeleteChatPhoto {
+    type Response = bool;
+    const NAME: &'static str = "deleteChatPhoto";
+}
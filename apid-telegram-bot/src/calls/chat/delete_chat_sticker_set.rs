@@ -0,0 +1,19 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to delete a group sticker set from a supergroup.
+/// The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights.
+/// Use the field *can_set_sticker_set* optionally returned in [getChat](crate::calls::GetChat) requests to check if the bot can use this method.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeleteChatStickerSet {
+    /// Unique identifier for the target chat or username of the target supergroup (in the format `@supergroupusername`)
+    pub chat_id: ChatId,
+}
+
+impl Call for DeleteChatStickerSet {
+    type Response = bool;
+    const NAME: &'static str = "deleteChatStickerSet";
+}
@@ -0,0 +1,38 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ChatId, ChatInviteLink};
+
+/// Use this method to edit a non-primary invite link created by the bot.
+/// The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights.
+/// Returns the edited invite link as a [`ChatInviteLink`] object.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct EditChatInviteLink {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// The invite link to edit
+    pub invite_link: String,
+
+    /// Invite link name; 0-32 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Point in time (Unix timestamp) when the link will expire
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<i32>,
+
+    /// The maximum number of users that can be members of the chat simultaneously after joining the chat via this invite link; 1-99999
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<i32>,
+
+    /// *True*, if users joining the chat via the link need to be approved by chat administrators.
+    /// If *True*, *member_limit* can't be specified
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub creates_join_request: bool,
+}
+
+impl Call for EditChatInviteLink {
+    type Response = ChatInviteLink;
+    const NAME: &'static str = "editChatInviteLink";
+}
@@ -0,0 +1,23 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to generate a new primary invite link for a chat; any previously generated primary link is revoked.
+/// The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights.
+/// Returns the new invite link as *String* on success.
+///
+/// **Note**: Each administrator in a chat generates their own invite links.
+/// Bots can't use invite links generated by other administrators.
+/// If you want your bot to work with invite links, it will need to generate its own link using [`ExportChatInviteLink`] or by calling the [getChat](https://core.telegram.org/bots/api#getchat) method.
+/// If your bot needs to generate a new primary invite link replacing its previous one, use [`ExportChatInviteLink`] again.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExportChatInviteLink {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+}
+
+impl Call for ExportChatInviteLink {
+    type Response = String;
+    const NAME: &'static str = "exportChatInviteLink";
+}
@@ -0,0 +1,18 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Chat, ChatId};
+
+/// Use this method to get up to date information about the chat
+/// (current name of the user for one-on-one conversations, current username of a user, group or channel, etc.).
+/// Returns a [`Chat`] object on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GetChat {
+    /// Unique identifier for the target chat or username of the target supergroup or channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+}
+
+impl Call for GetChat {
+    type Response = Chat;
+    const NAME: &'static str = "getChat";
+}
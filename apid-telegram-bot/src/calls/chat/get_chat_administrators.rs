@@ -0,0 +1,17 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ChatId, ChatMember};
+
+/// Use this method to get a list of administrators in a chat, which aren't bots.
+/// Returns an Array of [`ChatMember`] objects.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GetChatAdministrators {
+    /// Unique identifier for the target chat or username of the target supergroup or channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+}
+
+impl Call for GetChatAdministrators {
+    type Response = Vec<ChatMember>;
+    const NAME: &'static str = "getChatAdministrators";
+}
@@ -0,0 +1,21 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ChatId, ChatMember};
+
+/// Use this method to get information about a member of a chat.
+/// The method is only guaranteed to work for other users if the bot is an administrator in the chat.
+/// Returns a [`ChatMember`] object on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GetChatMember {
+    /// Unique identifier for the target chat or username of the target supergroup or channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Unique identifier of the target user
+    pub user_id: i64,
+}
+
+impl Call for GetChatMember {
+    type Response = ChatMember;
+    const NAME: &'static str = "getChatMember";
+}
@@ -0,0 +1,17 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to get the number of members in a chat.
+/// Returns *Int* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GetChatMemberCount {
+    /// Unique identifier for the target chat or username of the target supergroup or channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+}
+
+impl Call for GetChatMemberCount {
+    type Response = i32;
+    const NAME: &'static str = "getChatMemberCount";
+}
@@ -0,0 +1,17 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method for your bot to leave a group, supergroup or channel.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct LeaveChat {
+    /// Unique identifier for the target chat or username of the target supergroup or channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+}
+
+impl Call for LeaveChat {
+    type Response = bool;
+    const NAME: &'static str = "leaveChat";
+}
@@ -0,0 +1,57 @@
+pub use approve_chat_join_request::*;
+pub use ban_chat_member::*;
+pub use ban_chat_sender_chat::*;
+pub use create_chat_invite_link::*;
+pub use decline_chat_join_request::*;
+pub use delete_chat_photo::*;
+pub use delete_chat_sticker_set::*;
+pub use edit_chat_invite_link::*;
+pub use export_chat_invite_link::*;
+pub use get_chat::*;
+pub use get_chat_administrators::*;
+pub use get_chat_member::*;
+pub use get_chat_member_count::*;
+pub use leave_chat::*;
+pub use pin_chat_message::*;
+pub use promote_chat_member::*;
+pub use restrict_chat_member::*;
+pub use revoke_chat_invite_link::*;
+pub use set_chat_administrator_custom_title::*;
+pub use set_chat_description::*;
+pub use set_chat_permissions::*;
+pub use set_chat_photo::*;
+pub use set_chat_sticker_set::*;
+pub use set_chat_title::*;
+pub use unban_chat_member::*;
+pub use unban_chat_sender_chat::*;
+pub use unpin_all_chat_messages::*;
+pub use unpin_chat_message::*;
+
+mod approve_chat_join_request;
+mod ban_chat_member;
+mod ban_chat_sender_chat;
+mod create_chat_invite_link;
+mod decline_chat_join_request;
+mod delete_chat_photo;
+mod delete_chat_sticker_set;
+mod edit_chat_invite_link;
+mod export_chat_invite_link;
+mod get_chat;
+mod get_chat_administrators;
+mod get_chat_member;
+mod get_chat_member_count;
+mod leave_chat;
+mod pin_chat_message;
+mod promote_chat_member;
+mod restrict_chat_member;
+mod revoke_chat_invite_link;
+mod set_chat_administrator_custom_title;
+mod set_chat_description;
+mod set_chat_permissions;
+mod set_chat_photo;
+mod set_chat_sticker_set;
+mod set_chat_title;
+mod unban_chat_member;
+mod unban_chat_sender_chat;
+mod unpin_all_chat_messages;
+mod unpin_chat_message;
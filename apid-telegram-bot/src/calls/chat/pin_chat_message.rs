@@ -0,0 +1,26 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to add a message to the list of pinned messages in a chat.
+/// If the chat is not a private chat, the bot must be an administrator in the chat for this to work and must have the 'can_pin_messages' administrator right in a supergroup or 'can_edit_messages' administrator right in a channel.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct PinChatMessage {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Identifier of a message to pin
+    pub message_id: i32,
+
+    /// Pass *True* if it is not necessary to send a notification to all chat members about the new pinned message.
+    /// Notifications are always disabled in channels and private chats.
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub disable_notification: bool,
+}
+
+impl Call for PinChatMessage {
+    type Response = bool;
+    const NAME: &'static str = "pinChatMessage";
+}
@@ -0,0 +1,68 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to promote or demote a user in a supergroup or a channel.
+/// The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights.
+/// Pass *False* for all boolean parameters to demote a user.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct PromoteChatMember {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Unique identifier of the target user
+    pub user_id: i64,
+
+    /// Pass *True* if the administrator's presence in the chat is hidden
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub is_anonymous: bool,
+
+    /// Pass *True* if the administrator can access the chat event log, chat statistics, message statistics in channels, see channel members, see anonymous administrators in supergroups and ignore slow mode.
+    /// Implied by any other administrator privilege
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_manage_chat: bool,
+
+    /// Pass *True* if the administrator can create channel posts, channels only
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_post_messages: bool,
+
+    /// Pass *True* if the administrator can edit messages of other users and can pin messages, channels only
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_edit_messages: bool,
+
+    /// Pass *True* if the administrator can delete messages of other users
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_delete_messages: bool,
+
+    /// Pass *True* if the administrator can manage video chats
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_manage_video_chats: bool,
+
+    /// Pass *True* if the administrator can restrict, ban or unban chat members
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_restrict_members: bool,
+
+    /// Pass *True* if the administrator can add new administrators with a subset of their own privileges or demote administrators that he has promoted,
+    /// directly or indirectly (promoted by administrators that were appointed by him)
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_promote_members: bool,
+
+    /// Pass *True* if the administrator can change chat title, photo and other settings
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_change_info: bool,
+
+    /// Pass *True* if the administrator can invite new users to the chat
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_invite_users: bool,
+
+    /// Pass *True* if the administrator can pin messages, supergroups only
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_pin_messages: bool,
+}
+
+impl Call for PromoteChatMember {
+    type Response = bool;
+    const NAME: &'static str = "promoteChatMember";
+}
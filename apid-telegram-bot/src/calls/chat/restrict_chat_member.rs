@@ -0,0 +1,30 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ChatId, ChatPermissions};
+
+/// Use this method to restrict a user in a supergroup.
+/// The bot must be an administrator in the supergroup for this to work and must have the appropriate administrator rights.
+/// Pass *True* for all permissions to lift restrictions from a user.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RestrictChatMember {
+    /// Unique identifier for the target chat or username of the target supergroup (in the format `@supergroupusername`)
+    pub chat_id: ChatId,
+
+    /// Unique identifier of the target user
+    pub user_id: i64,
+
+    /// New user permissions
+    pub permissions: ChatPermissions,
+
+    /// Date when restrictions will be lifted for the user, unix time.
+    /// If user is restricted for more than 366 days or less than 30 seconds from the current time, they are considered to be restricted forever
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_date: Option<i32>,
+}
+
+impl Call for RestrictChatMember {
+    type Response = bool;
+    const NAME: &'static str = "restrictChatMember";
+}
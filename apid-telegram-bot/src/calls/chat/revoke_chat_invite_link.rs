@@ -0,0 +1,22 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ChatId, ChatInviteLink};
+
+/// Use this method to revoke an invite link created by the bot.
+/// If the primary link is revoked, a new link is automatically generated.
+/// The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights.
+/// Returns the revoked invite link as [`ChatInviteLink`] object.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RevokeChatInviteLink {
+    /// Unique identifier of the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// The invite link to revoke
+    pub invite_link: String,
+}
+
+impl Call for RevokeChatInviteLink {
+    type Response = ChatInviteLink;
+    const NAME: &'static str = "revokeChatInviteLink";
+}
@@ -0,0 +1,23 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to set a custom title for an administrator in a supergroup promoted by the bot.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SetChatAdministratorCustomTitle {
+    /// Unique identifier for the target chat or username of the target supergroup (in the format `@supergroupusername`)
+    pub chat_id: ChatId,
+
+    /// Unique identifier of the target user
+    pub user_id: i64,
+
+    /// New custom title for the administrator; 0-16 characters, emoji are not allowed
+    pub custom_title: String,
+}
+
+impl Call for SetChatAdministratorCustomTitle {
+    type Response = bool;
+    const NAME: &'static str = "setChatAdministratorCustomTitle";
+}
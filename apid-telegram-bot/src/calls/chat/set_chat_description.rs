@@ -0,0 +1,22 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to change the description of a group, a supergroup or a channel.
+/// The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SetChatDescription {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// New chat description, 0-255 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Call for SetChatDescription {
+    type Response = bool;
+    const NAME: &'static str = "setChatDescription";
+}
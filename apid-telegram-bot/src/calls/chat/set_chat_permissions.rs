@@ -0,0 +1,21 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ChatId, ChatPermissions};
+
+/// Use this method to set default chat permissions for all members.
+/// The bot must be an administrator in the group or a supergroup for this to work and must have the *can_restrict_members* administrator rights.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SetChatPermissions {
+    /// Unique identifier for the target chat or username of the target supergroup (in the format `@supergroupusername`)
+    pub chat_id: ChatId,
+
+    /// New default chat permissions
+    pub permissions: ChatPermissions,
+}
+
+impl Call for SetChatPermissions {
+    type Response = bool;
+    const NAME: &'static str = "setChatPermissions";
+}
@@ -0,0 +1,27 @@
+use apid::{Attachment, Call};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ChatId, InputFileUpload};
+
+/// Use this method to set a new profile photo for the chat.
+/// Photos can't be changed for private chats.
+/// The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SetChatPhoto {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// New chat photo, uploaded using multipart/form-data.
+    /// A *file_id* or a URL can't be used here.
+    pub photo: InputFileUpload,
+}
+
+impl Call for SetChatPhoto {
+    type Response = bool;
+    const NAME: &'static str = "setChatPhoto";
+
+    fn attachments(&self) -> Vec<Attachment<'_>> {
+        vec![self.photo.attachment()]
+    }
+}
@@ -0,0 +1,22 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to set a new group sticker set for a supergroup.
+/// The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights.
+/// Use the field *can_set_sticker_set* optionally returned in [getChat](crate::calls::GetChat) requests to check if the bot can use this method.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SetChatStickerSet {
+    /// Unique identifier for the target chat or username of the target supergroup (in the format `@supergroupusername`)
+    pub chat_id: ChatId,
+
+    /// Name of the sticker set to be set as the group sticker set
+    pub sticker_set_name: String,
+}
+
+impl Call for SetChatStickerSet {
+    type Response = bool;
+    const NAME: &'static str = "setChatStickerSet";
+}
@@ -0,0 +1,22 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to change the title of a chat.
+/// Titles can't be changed for private chats.
+/// The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SetChatTitle {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// New chat title, 1-255 characters
+    pub title: String,
+}
+
+impl Call for SetChatTitle {
+    type Response = bool;
+    const NAME: &'static str = "setChatTitle";
+}
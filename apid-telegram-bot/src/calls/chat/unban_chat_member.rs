@@ -0,0 +1,29 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to unban a previously banned user in a supergroup or channel.
+/// The user will **not** return to the group or channel automatically, but will be able to join via link, etc.
+/// The bot must be an administrator for this to work.
+/// By default, this method guarantees that after the call the user is not a member of the chat, but will be able to join it.
+/// So if the user is a member of the chat they will also be **removed** from the chat.
+/// If you don't want this, use the parameter *only_if_banned*.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct UnbanChatMember {
+    /// Unique identifier for the target group or username of the target supergroup or channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Unique identifier of the target user
+    pub user_id: i64,
+
+    /// Do nothing if the user is not banned
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub only_if_banned: bool,
+}
+
+impl Call for UnbanChatMember {
+    type Response = bool;
+    const NAME: &'static str = "unbanChatMember";
+}
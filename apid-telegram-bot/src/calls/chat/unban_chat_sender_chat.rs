@@ -0,0 +1,21 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to unban a previously banned channel chat in a supergroup or channel.
+/// The bot must be an administrator for this to work and must have the appropriate administrator rights.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct UnbanChatSenderChat {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Unique identifier of the target sender chat
+    pub sender_chat_id: i64,
+}
+
+impl Call for UnbanChatSenderChat {
+    type Response = bool;
+    const NAME: &'static str = "unbanChatSenderChat";
+}
@@ -0,0 +1,18 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to clear the list of pinned messages in a chat.
+/// If the chat is not a private chat, the bot must be an administrator in the chat for this to work and must have the 'can_pin_messages' administrator right in a supergroup or 'can_edit_messages' administrator right in a channel.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct UnpinAllChatMessages {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+}
+
+impl Call for UnpinAllChatMessages {
+    type Response = bool;
+    const NAME: &'static str = "unpinAllChatMessages";
+}
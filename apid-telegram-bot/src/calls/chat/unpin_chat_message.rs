@@ -0,0 +1,23 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// Use this method to remove a message from the list of pinned messages in a chat.
+/// If the chat is not a private chat, the bot must be an administrator in the chat for this to work and must have the 'can_pin_messages' administrator right in a supergroup or 'can_edit_messages' administrator right in a channel.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct UnpinChatMessage {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Identifier of a message to unpin.
+    /// If not specified, the most recent pinned message (by sending date) will be unpinned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<i32>,
+}
+
+impl Call for UnpinChatMessage {
+    type Response = bool;
+    const NAME: &'static str = "unpinChatMessage";
+}
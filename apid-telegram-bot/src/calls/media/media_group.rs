@@ -0,0 +1,131 @@
+use std::{collections::HashSet, fmt};
+
+use apid::Attachment;
+use serde::{Deserialize, Serialize};
+
+use crate::types::InputMedia;
+
+/// The media sent as an album by [`SendMediaGroup`](crate::calls::SendMediaGroup).
+///
+/// A media group must include 2-10 items, where documents and audio files can be only grouped in an album with messages of the same type,
+/// photos and videos can be grouped together, and animations can't be sent as an album.
+/// Every uploaded file must be attached under a unique name.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<InputMedia>")]
+pub struct MediaGroup(Vec<InputMedia>);
+
+impl MediaGroup {
+    /// The minimum number of items in a media group
+    pub const MIN_LEN: usize = 2;
+
+    /// The maximum number of items in a media group
+    pub const MAX_LEN: usize = 10;
+
+    /// Validates the media to be sent as an album.
+    pub fn new(media: Vec<InputMedia>) -> Result<Self, MediaGroupError> {
+        if !(Self::MIN_LEN..=Self::MAX_LEN).contains(&media.len()) {
+            return Err(MediaGroupError::InvalidLength(media.len()));
+        }
+
+        let kinds = media
+            .iter()
+            .map(MediaGroupKind::of)
+            .collect::<Option<Vec<_>>>()
+            .ok_or(MediaGroupError::Animation)?;
+        if kinds.windows(2).any(|pair| pair[0] != pair[1]) {
+            return Err(MediaGroupError::IncompatibleMedia);
+        }
+
+        let mut names = HashSet::new();
+        for attachment in media.iter().flat_map(InputMedia::attachments) {
+            if !names.insert(attachment.name) {
+                return Err(MediaGroupError::DuplicateAttachment(
+                    attachment.name.to_string(),
+                ));
+            }
+        }
+
+        Ok(MediaGroup(media))
+    }
+
+    /// The media in the album
+    pub fn media(&self) -> &[InputMedia] {
+        &self.0
+    }
+
+    /// Returns the media in the album.
+    pub fn into_inner(self) -> Vec<InputMedia> {
+        self.0
+    }
+
+    /// The files to be uploaded along with the album
+    pub fn attachments(&self) -> Vec<Attachment<'_>> {
+        self.0.iter().flat_map(InputMedia::attachments).collect()
+    }
+}
+
+impl TryFrom<Vec<InputMedia>> for MediaGroup {
+    type Error = MediaGroupError;
+
+    fn try_from(media: Vec<InputMedia>) -> Result<Self, Self::Error> {
+        MediaGroup::new(media)
+    }
+}
+
+/// Which media can be grouped together in an album.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MediaGroupKind {
+    Audio,
+    Document,
+    PhotoOrVideo,
+}
+
+impl MediaGroupKind {
+    fn of(media: &InputMedia) -> Option<Self> {
+        match media {
+            InputMedia::Animation(_) => None,
+            InputMedia::Audio(_) => Some(MediaGroupKind::Audio),
+            InputMedia::Document(_) => Some(MediaGroupKind::Document),
+            InputMedia::Photo(_) | InputMedia::Video(_) => Some(MediaGroupKind::PhotoOrVideo),
+        }
+    }
+}
+
+/// The media can't be sent as an album.
+#[derive(Debug, PartialEq, Eq)]
+pub enum MediaGroupError {
+    /// The album doesn't include 2-10 items
+    InvalidLength(usize),
+
+    /// The album includes an animation
+    Animation,
+
+    /// The album mixes documents or audio files with other types of media
+    IncompatibleMedia,
+
+    /// Several files are attached under the same name
+    DuplicateAttachment(String),
+}
+
+impl fmt::Display for MediaGroupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MediaGroupError::InvalidLength(len) => write!(
+                f,
+                "media group must include {}-{} items, got {}",
+                MediaGroup::MIN_LEN,
+                MediaGroup::MAX_LEN,
+                len
+            ),
+            MediaGroupError::Animation => f.write_str("animations can't be sent in a media group"),
+            MediaGroupError::IncompatibleMedia => f.write_str(
+                "documents and audio files can be only grouped with media of the same type",
+            ),
+            MediaGroupError::DuplicateAttachment(name) => {
+                write!(f, "several files are attached as `{}`", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MediaGroupError {}
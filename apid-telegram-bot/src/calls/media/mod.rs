@@ -0,0 +1,19 @@
+pub use media_group::*;
+pub use send_animation::*;
+pub use send_audio::*;
+pub use send_document::*;
+pub use send_media_group::*;
+pub use send_photo::*;
+pub use send_video::*;
+pub use send_video_note::*;
+pub use send_voice::*;
+
+mod media_group;
+mod send_animation;
+mod send_audio;
+mod send_document;
+mod send_media_group;
+mod send_photo;
+mod send_video;
+mod send_video_note;
+mod send_voice;
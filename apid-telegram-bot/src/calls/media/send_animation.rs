@@ -0,0 +1,82 @@
+use apid::{Attachment, Call};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Caption, ChatId, InputFile, InputFileUpload, Message, ParseMode, ReplyMarkup};
+
+/// Use this method to send animation files (GIF or H.264/MPEG-4 AVC video without sound).
+/// On success, the sent [`Message`] is returned.
+/// Bots can currently send animation files of up to 50 MB in size, this limit may be changed in the future.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SendAnimation {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Animation to send. Pass a file_id to send a file that exists on the Telegram servers (recommended),
+    /// pass an HTTP URL for Telegram to get a file from the Internet, or upload a new one.
+    pub animation: InputFile,
+
+    /// Duration of sent animation in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i32>,
+
+    /// Animation width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i32>,
+
+    /// Animation height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i32>,
+
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side.
+    /// The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320.
+    /// Thumbnails can't be reused and can be only uploaded as a new file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<InputFileUpload>,
+
+    /// Animation caption (may also be used when resending animations by *file_id*), 0-1024 characters after entities parsing,
+    /// with special entities that appear in the caption
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Caption>,
+
+    /// Mode for parsing entities in the animation caption.
+    /// See formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
+    /// Users will receive a notification with no sound.
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub disable_notification: bool,
+
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub protect_content: bool,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+
+    /// Pass *True*, if the message should be sent even if the specified replied-to message is not found
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub allow_sending_without_reply: bool,
+
+    /// Additional interface options.
+    /// An [inline keyboard](https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating),
+    /// [custom reply keyboard](https://core.telegram.org/bots#keyboards),
+    /// instructions to remove reply keyboard or to force a reply from the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl Call for SendAnimation {
+    type Response = Message;
+    const NAME: &'static str = "sendAnimation";
+
+    fn attachments(&self) -> Vec<Attachment<'_>> {
+        self.animation
+            .attachment()
+            .into_iter()
+            .chain(self.thumb.iter().map(InputFileUpload::attachment))
+            .collect()
+    }
+}
@@ -0,0 +1,74 @@
+use apid::{Attachment, Call};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Caption, ChatId, InputFile, InputFileUpload, Message, ParseMode, ReplyMarkup};
+
+/// Use this method to send general files.
+/// On success, the sent [`Message`] is returned.
+/// Bots can currently send files of any type of up to 50 MB in size, this limit may be changed in the future.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SendDocument {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended),
+    /// pass an HTTP URL for Telegram to get a file from the Internet, or upload a new one.
+    pub document: InputFile,
+
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side.
+    /// The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320.
+    /// Thumbnails can't be reused and can be only uploaded as a new file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<InputFileUpload>,
+
+    /// Document caption (may also be used when resending documents by *file_id*), 0-1024 characters after entities parsing,
+    /// with special entities that appear in the caption
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Caption>,
+
+    /// Mode for parsing entities in the document caption.
+    /// See formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// Disables automatic server-side content type detection for files uploaded using multipart/form-data
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub disable_content_type_detection: bool,
+
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
+    /// Users will receive a notification with no sound.
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub disable_notification: bool,
+
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub protect_content: bool,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+
+    /// Pass *True*, if the message should be sent even if the specified replied-to message is not found
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub allow_sending_without_reply: bool,
+
+    /// Additional interface options.
+    /// An [inline keyboard](https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating),
+    /// [custom reply keyboard](https://core.telegram.org/bots#keyboards),
+    /// instructions to remove reply keyboard or to force a reply from the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl Call for SendDocument {
+    type Response = Message;
+    const NAME: &'static str = "sendDocument";
+
+    fn attachments(&self) -> Vec<Attachment<'_>> {
+        self.document
+            .attachment()
+            .into_iter()
+            .chain(self.thumb.iter().map(InputFileUpload::attachment))
+            .collect()
+    }
+}
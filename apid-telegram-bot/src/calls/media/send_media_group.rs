@@ -0,0 +1,45 @@
+use apid::{Attachment, Call};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    calls::MediaGroup,
+    types::{ChatId, Message},
+};
+
+/// Use this method to send a group of photos, videos, documents or audios as an album.
+/// Documents and audio files can be only grouped in an album with messages of the same type.
+/// On success, an array of [`Message`]s that were sent is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SendMediaGroup {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// A JSON-serialized array describing messages to be sent, must include 2-10 items
+    pub media: MediaGroup,
+
+    /// Sends messages [silently](https://telegram.org/blog/channels-2-0#silent-messages).
+    /// Users will receive a notification with no sound.
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub disable_notification: bool,
+
+    /// Protects the contents of the sent messages from forwarding and saving
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub protect_content: bool,
+
+    /// If the messages are a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+
+    /// Pass *True*, if the message should be sent even if the specified replied-to message is not found
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub allow_sending_without_reply: bool,
+}
+
+impl Call for SendMediaGroup {
+    type Response = Vec<Message>;
+    const NAME: &'static str = "sendMediaGroup";
+
+    fn attachments(&self) -> Vec<Attachment<'_>> {
+        self.media.attachments()
+    }
+}
@@ -0,0 +1,61 @@
+use apid::{Attachment, Call};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Caption, ChatId, InputFile, Message, ParseMode, ReplyMarkup};
+
+/// Use this method to send photos.
+/// On success, the sent [`Message`] is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SendPhoto {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Photo to send. Pass a file_id to send a file that exists on the Telegram servers (recommended),
+    /// pass an HTTP URL for Telegram to get a file from the Internet, or upload a new one.
+    /// The photo must be at most 10 MB in size. The photo's width and height must not exceed 10000 in total.
+    /// Width and height ratio must be at most 20.
+    pub photo: InputFile,
+
+    /// Photo caption (may also be used when resending photos by *file_id*), 0-1024 characters after entities parsing,
+    /// with special entities that appear in the caption
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Caption>,
+
+    /// Mode for parsing entities in the photo caption.
+    /// See formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
+    /// Users will receive a notification with no sound.
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub disable_notification: bool,
+
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub protect_content: bool,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+
+    /// Pass *True*, if the message should be sent even if the specified replied-to message is not found
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub allow_sending_without_reply: bool,
+
+    /// Additional interface options.
+    /// An [inline keyboard](https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating),
+    /// [custom reply keyboard](https://core.telegram.org/bots#keyboards),
+    /// instructions to remove reply keyboard or to force a reply from the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl Call for SendPhoto {
+    type Response = Message;
+    const NAME: &'static str = "sendPhoto";
+
+    fn attachments(&self) -> Vec<Attachment<'_>> {
+        self.photo.attachment().into_iter().collect()
+    }
+}
@@ -0,0 +1,68 @@
+use apid::{Attachment, Call};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ChatId, InputFile, InputFileUpload, Message, ReplyMarkup};
+
+/// As of [v.4.0](https://telegram.org/blog/video-messages-and-telescope), Telegram clients support rounded square MPEG4 videos of up to 1 minute long.
+/// Use this method to send video messages.
+/// On success, the sent [`Message`] is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SendVideoNote {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Video note to send. Pass a file_id to send a video note that exists on the Telegram servers (recommended) or upload a new video.
+    /// Sending video notes by a URL is currently unsupported
+    pub video_note: InputFile,
+
+    /// Duration of sent video in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i32>,
+
+    /// Video width and height, i.e. diameter of the video message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<i32>,
+
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side.
+    /// The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320.
+    /// Thumbnails can't be reused and can be only uploaded as a new file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<InputFileUpload>,
+
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
+    /// Users will receive a notification with no sound.
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub disable_notification: bool,
+
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub protect_content: bool,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+
+    /// Pass *True*, if the message should be sent even if the specified replied-to message is not found
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub allow_sending_without_reply: bool,
+
+    /// Additional interface options.
+    /// An [inline keyboard](https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating),
+    /// [custom reply keyboard](https://core.telegram.org/bots#keyboards),
+    /// instructions to remove reply keyboard or to force a reply from the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl Call for SendVideoNote {
+    type Response = Message;
+    const NAME: &'static str = "sendVideoNote";
+
+    fn attachments(&self) -> Vec<Attachment<'_>> {
+        self.video_note
+            .attachment()
+            .into_iter()
+            .chain(self.thumb.iter().map(InputFileUpload::attachment))
+            .collect()
+    }
+}
@@ -0,0 +1,61 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Caption, ChatId, MessageId, ParseMode, ReplyMarkup};
+
+/// Use this method to copy messages of any kind.
+/// Service messages and invoice messages can't be copied.
+/// The method is analogous to the method [`ForwardMessage`](crate::calls::ForwardMessage),
+/// but the copied message doesn't have a link to the original message.
+/// Returns the [`MessageId`] of the sent message on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct CopyMessage {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Unique identifier for the chat where the original message was sent (or channel username in the format `@channelusername`)
+    pub from_chat_id: ChatId,
+
+    /// Message identifier in the chat specified in *from_chat_id*
+    pub message_id: i32,
+
+    /// New caption for media, 0-1024 characters after entities parsing,
+    /// with special entities that appear in the new caption.
+    /// If not specified, the original caption is kept
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Caption>,
+
+    /// Mode for parsing entities in the new caption.
+    /// See formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
+    /// Users will receive a notification with no sound.
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub disable_notification: bool,
+
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub protect_content: bool,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+
+    /// Pass *True*, if the message should be sent even if the specified replied-to message is not found
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub allow_sending_without_reply: bool,
+
+    /// Additional interface options.
+    /// An [inline keyboard](https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating),
+    /// [custom reply keyboard](https://core.telegram.org/bots#keyboards),
+    /// instructions to remove reply keyboard or to force a reply from the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl Call for CopyMessage {
+    type Response = MessageId;
+    const NAME: &'static str = "copyMessage";
+}
@@ -0,0 +1,36 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    calls::{EditedMessage, MessageTarget},
+    types::{Caption, InlineKeyboardMarkup, ParseMode},
+};
+
+/// Use this method to edit captions of messages.
+/// On success, if the edited message is not an inline message, the edited [`Message`](crate::types::Message) is returned, otherwise *True* is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct EditMessageCaption {
+    /// The message to edit
+    #[serde(flatten)]
+    pub target: MessageTarget,
+
+    /// New caption of the message, 0-1024 characters after entities parsing,
+    /// with special entities that appear in the caption.
+    /// The caption is removed if not specified.
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Caption>,
+
+    /// Mode for parsing entities in the message caption.
+    /// See formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+impl Call for EditMessageCaption {
+    type Response = EditedMessage;
+    const NAME: &'static str = "editMessageCaption";
+}
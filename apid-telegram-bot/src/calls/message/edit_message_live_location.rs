@@ -0,0 +1,46 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    calls::{EditedMessage, MessageTarget},
+    types::InlineKeyboardMarkup,
+};
+
+/// Use this method to edit live location messages.
+/// A location can be edited until its *live_period* expires or editing is explicitly disabled by a call to [`StopMessageLiveLocation`](crate::calls::StopMessageLiveLocation).
+/// On success, if the edited message is not an inline message, the edited [`Message`](crate::types::Message) is returned, otherwise *True* is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct EditMessageLiveLocation {
+    /// The message to edit
+    #[serde(flatten)]
+    pub target: MessageTarget,
+
+    /// Latitude of new location
+    pub latitude: f64,
+
+    /// Longitude of new location
+    pub longitude: f64,
+
+    /// The radius of uncertainty for the location, measured in meters; 0-1500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+
+    /// Direction in which the user is moving, in degrees.
+    /// Must be between 1 and 360 if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i32>,
+
+    /// The maximum distance for proximity alerts about approaching another chat member, in meters.
+    /// Must be between 1 and 100000 if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i32>,
+
+    /// A JSON-serialized object for a new [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+impl Call for EditMessageLiveLocation {
+    type Response = EditedMessage;
+    const NAME: &'static str = "editMessageLiveLocation";
+}
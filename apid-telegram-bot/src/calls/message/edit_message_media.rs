@@ -0,0 +1,35 @@
+use apid::{Attachment, Call};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    calls::{EditedMessage, MessageTarget},
+    types::{InlineKeyboardMarkup, InputMedia},
+};
+
+/// Use this method to edit animation, audio, document, photo, or video messages.
+/// If a message is part of a message album, then it can be edited only to an audio for audio albums,
+/// only to a document for document albums and to a photo or a video otherwise.
+/// When an inline message is edited, a new file can't be uploaded; use a previously uploaded file via its file_id or specify a URL.
+/// On success, if the edited message is not an inline message, the edited [`Message`](crate::types::Message) is returned, otherwise *True* is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct EditMessageMedia {
+    /// The message to edit
+    #[serde(flatten)]
+    pub target: MessageTarget,
+
+    /// A JSON-serialized object for a new media content of the message
+    pub media: InputMedia,
+
+    /// A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+impl Call for EditMessageMedia {
+    type Response = EditedMessage;
+    const NAME: &'static str = "editMessageMedia";
+
+    fn attachments(&self) -> Vec<Attachment<'_>> {
+        self.media.attachments()
+    }
+}
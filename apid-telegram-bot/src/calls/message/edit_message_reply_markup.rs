@@ -0,0 +1,25 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    calls::{EditedMessage, MessageTarget},
+    types::InlineKeyboardMarkup,
+};
+
+/// Use this method to edit only the reply markup of messages.
+/// On success, if the edited message is not an inline message, the edited [`Message`](crate::types::Message) is returned, otherwise *True* is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct EditMessageReplyMarkup {
+    /// The message to edit
+    #[serde(flatten)]
+    pub target: MessageTarget,
+
+    /// A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+impl Call for EditMessageReplyMarkup {
+    type Response = EditedMessage;
+    const NAME: &'static str = "editMessageReplyMarkup";
+}
@@ -0,0 +1,41 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    calls::{EditedMessage, MessageTarget},
+    types::{InlineKeyboardMarkup, MessageEntity, ParseMode},
+};
+
+/// Use this method to edit text and [game](https://core.telegram.org/bots/api#games) messages.
+/// On success, if the edited message is not an inline message, the edited [`Message`](crate::types::Message) is returned, otherwise *True* is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct EditMessageText {
+    /// The message to edit
+    #[serde(flatten)]
+    pub target: MessageTarget,
+
+    /// New text of the message, 1-4096 characters after entities parsing
+    pub text: String,
+
+    /// Mode for parsing entities in the message text.
+    /// See formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// A list of special entities that appear in message text, which can be specified instead of *parse_mode*
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entities: Vec<MessageEntity>,
+
+    /// Disables link previews for links in this message
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub disable_web_page_preview: bool,
+
+    /// A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+impl Call for EditMessageText {
+    type Response = EditedMessage;
+    const NAME: &'static str = "editMessageText";
+}
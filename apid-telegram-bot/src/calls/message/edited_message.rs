@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Message, True};
+
+/// The result of editing a message:
+/// if the edited message is not an inline message, the edited [`Message`] is returned, otherwise *True* is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum EditedMessage {
+    /// The edited message sent by the bot
+    Chat(Box<Message>),
+
+    /// The inline message was edited
+    Inline(True),
+}
@@ -0,0 +1,33 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ChatId, Message};
+
+/// Use this method to forward messages of any kind.
+/// Service messages can't be forwarded.
+/// On success, the sent [`Message`] is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ForwardMessage {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Unique identifier for the chat where the original message was sent (or channel username in the format `@channelusername`)
+    pub from_chat_id: ChatId,
+
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
+    /// Users will receive a notification with no sound.
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub disable_notification: bool,
+
+    /// Protects the contents of the forwarded message from forwarding and saving
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub protect_content: bool,
+
+    /// Message identifier in the chat specified in *from_chat_id*
+    pub message_id: i32,
+}
+
+impl Call for ForwardMessage {
+    type Response = Message;
+    const NAME: &'static str = "forwardMessage";
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::ChatId;
+
+/// The message to edit, either sent by the bot or sent via the bot (for [inline bots](https://core.telegram.org/bots/api#inline-mode)).
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MessageTarget {
+    /// A message sent by the bot
+    Chat {
+        /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+        chat_id: ChatId,
+
+        /// Identifier of the message
+        message_id: i32,
+    },
+
+    /// A message sent via the bot
+    Inline {
+        /// Identifier of the inline message
+        inline_message_id: String,
+    },
+}
@@ -1,3 +1,39 @@
+pub use copy_message::*;
+pub use delete_message::*;
+pub use edit_message_caption::*;
+pub use edit_message_live_location::*;
+pub use edit_message_media::*;
+pub use edit_message_reply_markup::*;
+pub use edit_message_text::*;
+pub use edited_message::*;
+pub use forward_message::*;
+pub use message_target::*;
+pub use send_chat_action::*;
+pub use send_contact::*;
+pub use send_dice::*;
+pub use send_location::*;
 pub use send_message::*;
+pub use send_poll::*;
+pub use send_venue::*;
+pub use stop_message_live_location::*;
+pub use stop_poll::*;
 
+mod copy_message;
+mod delete_message;
+mod edit_message_caption;
+mod edit_message_live_location;
+mod edit_message_media;
+mod edit_message_reply_markup;
+mod edit_message_text;
+mod edited_message;
+mod forward_message;
+mod message_target;
+mod send_chat_action;
+mod send_contact;
+mod send_dice;
+mod send_location;
 mod send_message;
+mod send_poll;
+mod send_venue;
+mod stop_message_live_location;
+mod stop_poll;
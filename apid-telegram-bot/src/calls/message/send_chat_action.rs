@@ -0,0 +1,24 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ChatAction, ChatId};
+
+/// Use this method when you need to tell the user that something is happening on the bot's side.
+/// The status is set for 5 seconds or less (when a message arrives from your bot, Telegram clients clear its typing status).
+/// Returns *True* on success.
+///
+/// We only recommend using this method when a response from the bot will take a **noticeable** amount of time to arrive.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SendChatAction {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Type of action to broadcast.
+    /// Choose one, depending on what the user is about to receive
+    pub action: ChatAction,
+}
+
+impl Call for SendChatAction {
+    type Response = bool;
+    const NAME: &'static str = "sendChatAction";
+}
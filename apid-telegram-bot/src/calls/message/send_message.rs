@@ -1,7 +1,7 @@
 use apid::Call;
 use serde::{Deserialize, Serialize};
 
-use crate::types::{ChatId, Message};
+use crate::types::{ChatId, Message, MessageEntity, ParseMode, ReplyMarkup};
 
 /// Use this method to send text messages. On success, the sent [`Message`] is returned.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -14,14 +14,43 @@ pub struct SendMessage {
 
     /// Mode for parsing entities in the message text.
     /// See formatting options for more details.
-    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
-}
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case", tag = "parse_mode")]
-pub enum ParseMode {}
+    /// A list of special entities that appear in message text, which can be specified instead of *parse_mode*
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entities: Vec<MessageEntity>,
+
+    /// Disables link previews for links in this message
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub disable_web_page_preview: bool,
+
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
+    /// Users will receive a notification with no sound.
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub disable_notification: bool,
+
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub protect_content: bool,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+
+    /// Pass *True*, if the message should be sent even if the specified replied-to message is not found
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub allow_sending_without_reply: bool,
+
+    /// Additional interface options.
+    /// An [inline keyboard](https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating),
+    /// [custom reply keyboard](https://core.telegram.org/bots#keyboards),
+    /// instructions to remove reply keyboard or to force a reply from the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
 
 impl Call for SendMessage {
     type Response = Message;
+    const NAME: &'static str = "sendMessage";
 }
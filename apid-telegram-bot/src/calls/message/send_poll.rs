@@ -0,0 +1,80 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ChatId, Message, ParseMode, PollKind, ReplyMarkup};
+
+/// Use this method to send a native poll.
+/// On success, the sent [`Message`] is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SendPoll {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Poll question, 1-300 characters
+    pub question: String,
+
+    /// A JSON-serialized list of answer options, 2-10 strings 1-100 characters each
+    pub options: Vec<String>,
+
+    /// *True*, if the poll needs to be anonymous, defaults to *True*
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_anonymous: Option<bool>,
+
+    /// Poll type, “quiz” or “regular”, along with the options specific to it.
+    /// *correct_option_id* is required for quiz polls.
+    #[serde(flatten)]
+    pub kind: PollKind,
+
+    /// *True*, if the poll allows multiple answers, ignored for polls in quiz mode
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub allows_multiple_answers: bool,
+
+    /// Mode for parsing entities in the explanation.
+    /// See formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation_parse_mode: Option<ParseMode>,
+
+    /// Amount of time in seconds the poll will be active after creation, 5-600.
+    /// Can't be used together with *close_date*.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_period: Option<i32>,
+
+    /// Point in time (Unix timestamp) when the poll will be automatically closed.
+    /// Must be at least 5 and no more than 600 seconds in the future.
+    /// Can't be used together with *open_period*.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_date: Option<i32>,
+
+    /// Pass *True* if the poll needs to be immediately closed. This can be useful for poll preview.
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub is_closed: bool,
+
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
+    /// Users will receive a notification with no sound.
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub disable_notification: bool,
+
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub protect_content: bool,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+
+    /// Pass *True*, if the message should be sent even if the specified replied-to message is not found
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub allow_sending_without_reply: bool,
+
+    /// Additional interface options.
+    /// An [inline keyboard](https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating),
+    /// [custom reply keyboard](https://core.telegram.org/bots#keyboards),
+    /// instructions to remove reply keyboard or to force a reply from the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl Call for SendPoll {
+    type Response = Message;
+    const NAME: &'static str = "sendPoll";
+}
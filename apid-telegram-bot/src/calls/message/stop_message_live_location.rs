@@ -0,0 +1,25 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    calls::{EditedMessage, MessageTarget},
+    types::InlineKeyboardMarkup,
+};
+
+/// Use this method to stop updating a live location message before *live_period* expires.
+/// On success, if the message is not an inline message, the edited [`Message`](crate::types::Message) is returned, otherwise *True* is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct StopMessageLiveLocation {
+    /// The message to edit
+    #[serde(flatten)]
+    pub target: MessageTarget,
+
+    /// A JSON-serialized object for a new [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+impl Call for StopMessageLiveLocation {
+    type Response = EditedMessage;
+    const NAME: &'static str = "stopMessageLiveLocation";
+}
@@ -0,0 +1,24 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ChatId, InlineKeyboardMarkup, Poll};
+
+/// Use this method to stop a poll which was sent by the bot.
+/// On success, the stopped [`Poll`] is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct StopPoll {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Identifier of the original message with the poll
+    pub message_id: i32,
+
+    /// A JSON-serialized object for a new message [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+impl Call for StopPoll {
+    type Response = Poll;
+    const NAME: &'static str = "stopPoll";
+}
@@ -11,4 +11,5 @@ pub struct Close {}
 
 impl Call for Close {
     type Response = bool;
+    const NAME: &'static str = "close";
 }
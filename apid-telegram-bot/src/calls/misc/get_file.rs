@@ -0,0 +1,21 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::File;
+
+/// Use this method to get basic information about a file and prepare it for downloading.
+/// For the moment, bots can download files of up to 20MB in size.
+/// On success, a [`File`] object is returned.
+/// The file can then be downloaded via [`BotApi::file_location`](crate::BotApi::file_location),
+/// the link is guaranteed to be valid for at least 1 hour.
+/// When the link expires, a new one can be requested by calling [`GetFile`] again.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GetFile {
+    /// File identifier to get information about
+    pub file_id: String,
+}
+
+impl Call for GetFile {
+    type Response = File;
+    const NAME: &'static str = "getFile";
+}
@@ -11,4 +11,5 @@ pub struct GetMe {}
 
 impl Call for GetMe {
     type Response = User;
+    const NAME: &'static str = "getMe";
 }
@@ -11,4 +11,5 @@ pub struct LogOut {}
 
 impl Call for LogOut {
     type Response = bool;
+    const NAME: &'static str = "logOut";
 }
@@ -1,7 +1,9 @@
 pub use close::*;
+pub use get_file::*;
 pub use get_me::*;
 pub use log_out::*;
 
 mod close;
+mod get_file;
 mod get_me;
 mod log_out;
@@ -1,8 +1,20 @@
 //! This module contains types representing Telegram API request bodies.
+pub use bot::*;
+pub use chat::*;
+pub use media::*;
 pub use message::*;
 pub use misc::*;
+pub use passport::*;
+pub use payment::*;
+pub use sticker::*;
 pub use update::*;
 
+mod bot;
+mod chat;
+mod media;
 mod message;
 mod misc;
+mod passport;
+mod payment;
+mod sticker;
 mod update;
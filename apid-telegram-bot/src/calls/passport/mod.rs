@@ -0,0 +1,3 @@
+pub use set_passport_data_errors::*;
+
+mod set_passport_data_errors;
@@ -0,0 +1,26 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::PassportElementError;
+
+/// Informs a user that some of the Telegram Passport elements they provided contains errors.
+/// The user will not be able to re-submit their Passport to you until the errors are fixed
+/// (the contents of the field for which you returned the error must change).
+/// Returns *True* on success.
+///
+/// Use this if the data submitted by the user doesn't satisfy the standards your service requires for any reason.
+/// For example, if a birthday date seems invalid, a submitted document is blurry, a scan shows evidence of tampering, etc.
+/// Supply some details in the error message to make sure the user knows how to correct the issues.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SetPassportDataErrors {
+    /// User identifier
+    pub user_id: i64,
+
+    /// An array describing the errors
+    pub errors: Vec<PassportElementError>,
+}
+
+impl Call for SetPassportDataErrors {
+    type Response = bool;
+    const NAME: &'static str = "setPassportDataErrors";
+}
@@ -0,0 +1,47 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{False, True};
+
+/// Once the user has confirmed their payment and shipping details,
+/// the Bot API sends the final confirmation in the form of an [`Update`](crate::types::Update) with the field *pre_checkout_query*.
+/// Use this method to respond to such pre-checkout queries.
+/// On success, *True* is returned.
+///
+/// **Note**: The Bot API must receive an answer within 10 seconds after the pre-checkout query was sent.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct AnswerPreCheckoutQuery {
+    /// Unique identifier for the query to be answered
+    pub pre_checkout_query_id: String,
+
+    /// Whether everything is alright (goods are available, etc.) and the bot is ready to proceed with the order
+    #[serde(flatten)]
+    pub answer: PreCheckoutQueryAnswer,
+}
+
+/// The answer to a pre-checkout query.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PreCheckoutQueryAnswer {
+    /// Everything is alright and the bot is ready to proceed with the order
+    Ok {
+        /// *True*, if everything is alright
+        ok: True,
+    },
+
+    /// There are any problems
+    Err {
+        /// *False*, if there are any problems
+        ok: False,
+
+        /// Error message in human readable form that explains the reason for failure to proceed with the checkout
+        /// (e.g. "Sorry, somebody just bought the last of our amazing black T-shirts while you were busy filling out your payment details. Please choose a different color or garment!").
+        /// Telegram will display this message to the user.
+        error_message: String,
+    },
+}
+
+impl Call for AnswerPreCheckoutQuery {
+    type Response = bool;
+    const NAME: &'static str = "answerPreCheckoutQuery";
+}
@@ -0,0 +1,48 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{False, ShippingOption, True};
+
+/// If you sent an invoice requesting a shipping address and the parameter *is_flexible* was specified,
+/// the Bot API will send an [`Update`](crate::types::Update) with a *shipping_query* field to the bot.
+/// Use this method to reply to shipping queries.
+/// On success, *True* is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct AnswerShippingQuery {
+    /// Unique identifier for the query to be answered
+    pub shipping_query_id: String,
+
+    /// Whether delivery to the specified address is possible, and the details of it
+    #[serde(flatten)]
+    pub answer: ShippingQueryAnswer,
+}
+
+/// The answer to a shipping query.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ShippingQueryAnswer {
+    /// Delivery to the specified address is possible
+    Ok {
+        /// *True*, if delivery to the specified address is possible
+        ok: True,
+
+        /// Available shipping options
+        shipping_options: Vec<ShippingOption>,
+    },
+
+    /// There are any problems (for example, if delivery to the specified address is not possible)
+    Err {
+        /// *False*, if there are any problems
+        ok: False,
+
+        /// Error message in human readable form that explains why it is impossible to complete the order
+        /// (e.g. "Sorry, delivery to your desired address is unavailable').
+        /// Telegram will display this message to the user.
+        error_message: String,
+    },
+}
+
+impl Call for AnswerShippingQuery {
+    type Response = bool;
+    const NAME: &'static str = "answerShippingQuery";
+}
@@ -0,0 +1,9 @@
+pub use answer_pre_checkout_query::*;
+pub use answer_shipping_query::*;
+pub use create_invoice_link::*;
+pub use send_invoice::*;
+
+mod answer_pre_checkout_query;
+mod answer_shipping_query;
+mod create_invoice_link;
+mod send_invoice;
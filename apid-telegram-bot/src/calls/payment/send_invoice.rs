@@ -0,0 +1,130 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ChatId, InlineKeyboardMarkup, LabeledPrice, Message};
+
+/// Use this method to send invoices.
+/// On success, the sent [`Message`] is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SendInvoice {
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+    pub chat_id: ChatId,
+
+    /// Product name, 1-32 characters
+    pub title: String,
+
+    /// Product description, 1-255 characters
+    pub description: String,
+
+    /// Bot-defined invoice payload, 1-128 bytes.
+    /// This will not be displayed to the user, use for your internal processes.
+    pub payload: String,
+
+    /// Payments provider token, obtained via [@BotFather](https://t.me/botfather)
+    pub provider_token: String,
+
+    /// Three-letter ISO 4217 currency code, see [more on currencies](https://core.telegram.org/bots/payments#supported-currencies)
+    pub currency: String,
+
+    /// Price breakdown, a list of components (e.g. product price, tax, discount, delivery cost, delivery tax, bonus, etc.)
+    pub prices: Vec<LabeledPrice>,
+
+    /// The maximum accepted amount for tips in the *smallest units* of the currency (integer, **not** float/double).
+    /// For example, for a maximum tip of `US$ 1.45` pass `max_tip_amount = 145`.
+    /// See the *exp* parameter in [currencies.json](https://core.telegram.org/bots/payments/currencies.json),
+    /// it shows the number of digits past the decimal point for each currency (2 for the majority of currencies).
+    /// Defaults to 0
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tip_amount: Option<i32>,
+
+    /// A list of suggested amounts of tips in the *smallest units* of the currency (integer, **not** float/double).
+    /// At most 4 suggested tip amounts can be specified.
+    /// The suggested tip amounts must be positive, passed in a strictly increased order and must not exceed *max_tip_amount*.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suggested_tip_amounts: Vec<i32>,
+
+    /// Unique deep-linking parameter.
+    /// If left empty, **forwarded copies** of the sent message will have a *Pay* button, allowing multiple users to pay directly from the forwarded message, using the same invoice.
+    /// If non-empty, forwarded copies of the sent message will have a *URL* button with a deep link to the bot (instead of a *Pay* button), with the value used as the start parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_parameter: Option<String>,
+
+    /// JSON-serialized data about the invoice, which will be shared with the payment provider.
+    /// A detailed description of required fields should be provided by the payment provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_data: Option<String>,
+
+    /// URL of the product photo for the invoice.
+    /// Can be a photo of the goods or a marketing image for a service.
+    /// People like it better when they see what they are paying for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<String>,
+
+    /// Photo size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_size: Option<i32>,
+
+    /// Photo width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<i32>,
+
+    /// Photo height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<i32>,
+
+    /// Pass *True*, if you require the user's full name to complete the order
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub need_name: bool,
+
+    /// Pass *True*, if you require the user's phone number to complete the order
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub need_phone_number: bool,
+
+    /// Pass *True*, if you require the user's email address to complete the order
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub need_email: bool,
+
+    /// Pass *True*, if you require the user's shipping address to complete the order
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub need_shipping_address: bool,
+
+    /// Pass *True*, if the user's phone number should be sent to provider
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub send_phone_number_to_provider: bool,
+
+    /// Pass *True*, if the user's email address should be sent to provider
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub send_email_to_provider: bool,
+
+    /// Pass *True*, if the final price depends on the shipping method
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub is_flexible: bool,
+
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
+    /// Users will receive a notification with no sound.
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub disable_notification: bool,
+
+    /// Protects the contents of the sent message from forwarding and saving
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub protect_content: bool,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+
+    /// Pass *True*, if the message should be sent even if the specified replied-to message is not found
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub allow_sending_without_reply: bool,
+
+    /// An [inline keyboard](https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating).
+    /// If empty, one 'Pay `total price`' button will be shown.
+    /// If not empty, the first button must be a Pay button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+impl Call for SendInvoice {
+    type Response = Message;
+    const NAME: &'static str = "sendInvoice";
+}
@@ -0,0 +1,39 @@
+use apid::{Attachment, Call};
+use serde::{Deserialize, Serialize};
+
+use crate::{calls::StickerFile, types::MaskPosition};
+
+/// Use this method to add a new sticker to a set created by the bot.
+/// You **must** use exactly one of the fields *png_sticker*, *tgs_sticker*, or *webm_sticker*.
+/// Animated stickers can be added to animated sticker sets and only to them.
+/// Animated sticker sets can have up to 50 stickers.
+/// Static sticker sets can have up to 120 stickers.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct AddStickerToSet {
+    /// User identifier of sticker set owner
+    pub user_id: i64,
+
+    /// Sticker set name
+    pub name: String,
+
+    /// The sticker file, in one of the sticker formats
+    #[serde(flatten)]
+    pub sticker: StickerFile,
+
+    /// One or more emoji corresponding to the sticker
+    pub emojis: String,
+
+    /// An object for position where the mask should be placed on faces
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<MaskPosition>,
+}
+
+impl Call for AddStickerToSet {
+    type Response = bool;
+    const NAME: &'static str = "addStickerToSet";
+
+    fn attachments(&self) -> Vec<Attachment<'_>> {
+        self.sticker.file().attachment().into_iter().collect()
+    }
+}
@@ -0,0 +1,53 @@
+use apid::{Attachment, Call};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    calls::StickerFile,
+    types::{MaskPosition, StickerKind},
+};
+
+/// Use this method to create a new sticker set owned by a user.
+/// The bot will be able to edit the sticker set thus created.
+/// You **must** use exactly one of the fields *png_sticker*, *tgs_sticker*, or *webm_sticker*.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct CreateNewStickerSet {
+    /// User identifier of created sticker set owner
+    pub user_id: i64,
+
+    /// Short name of sticker set, to be used in `t.me/addstickers/` URLs (e.g., *animals*).
+    /// Can contain only English letters, digits and underscores.
+    /// Must begin with a letter, can't contain consecutive underscores and must end in `"_by_<bot_username>"`.
+    /// `<bot_username>` is case insensitive.
+    /// 1-64 characters.
+    pub name: String,
+
+    /// Sticker set title, 1-64 characters
+    pub title: String,
+
+    /// The sticker file, in one of the sticker formats
+    #[serde(flatten)]
+    pub sticker: StickerFile,
+
+    /// Type of stickers in the set, pass “regular” or “mask”.
+    /// Custom emoji sticker sets can't be created via the Bot API at the moment.
+    /// By default, a regular sticker set is created.
+    #[serde(rename = "sticker_type", skip_serializing_if = "Option::is_none")]
+    pub sticker_kind: Option<StickerKind>,
+
+    /// One or more emoji corresponding to the sticker
+    pub emojis: String,
+
+    /// An object for position where the mask should be placed on faces
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<MaskPosition>,
+}
+
+impl Call for CreateNewStickerSet {
+    type Response = bool;
+    const NAME: &'static str = "createNewStickerSet";
+
+    fn attachments(&self) -> Vec<Attachment<'_>> {
+        self.sticker.file().attachment().into_iter().collect()
+    }
+}
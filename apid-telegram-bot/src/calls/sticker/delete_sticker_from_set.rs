@@ -0,0 +1,15 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+/// Use this method to delete a sticker from a set created by the bot.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeleteStickerFromSet {
+    /// File identifier of the sticker
+    pub sticker: String,
+}
+
+impl Call for DeleteStickerFromSet {
+    type Response = bool;
+    const NAME: &'static str = "deleteStickerFromSet";
+}
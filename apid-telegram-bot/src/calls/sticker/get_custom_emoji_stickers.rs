@@ -0,0 +1,18 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::Sticker;
+
+/// Use this method to get information about custom emoji stickers by their identifiers.
+/// Returns an Array of [`Sticker`] objects.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GetCustomEmojiStickers {
+    /// List of custom emoji identifiers.
+    /// At most 200 custom emoji identifiers can be specified.
+    pub custom_emoji_ids: Vec<String>,
+}
+
+impl Call for GetCustomEmojiStickers {
+    type Response = Vec<Sticker>;
+    const NAME: &'static str = "getCustomEmojiStickers";
+}
@@ -0,0 +1,17 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+use crate::types::StickerSet;
+
+/// Use this method to get a sticker set.
+/// On success, a [`StickerSet`] object is returned.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GetStickerSet {
+    /// Name of the sticker set
+    pub name: String,
+}
+
+impl Call for GetStickerSet {
+    type Response = StickerSet;
+    const NAME: &'static str = "getStickerSet";
+}
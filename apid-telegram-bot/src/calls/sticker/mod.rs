@@ -0,0 +1,21 @@
+pub use add_sticker_to_set::*;
+pub use create_new_sticker_set::*;
+pub use delete_sticker_from_set::*;
+pub use get_custom_emoji_stickers::*;
+pub use get_sticker_set::*;
+pub use send_sticker::*;
+pub use set_sticker_position_in_set::*;
+pub use set_sticker_set_thumb::*;
+pub use sticker_file::*;
+pub use upload_sticker_file::*;
+
+mod add_sticker_to_set;
+mod create_new_sticker_set;
+mod delete_sticker_from_set;
+mod get_custom_emoji_stickers;
+mod get_sticker_set;
+mod send_sticker;
+mod set_sticker_position_in_set;
+mod set_sticker_set_thumb;
+mod sticker_file;
+mod upload_sticker_file;
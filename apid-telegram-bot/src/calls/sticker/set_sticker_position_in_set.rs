@@ -0,0 +1,18 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+/// Use this method to move a sticker in a set created by the bot to a specific position.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SetStickerPositionInSet {
+    /// File identifier of the sticker
+    pub sticker: String,
+
+    /// New sticker position in the set, zero-based
+    pub position: i32,
+}
+
+impl Call for SetStickerPositionInSet {
+    type Response = bool;
+    const NAME: &'static str = "setStickerPositionInSet";
+}
@@ -0,0 +1,40 @@
+use apid::{Attachment, Call};
+use serde::{Deserialize, Serialize};
+
+use crate::types::InputFile;
+
+/// Use this method to set the thumbnail of a sticker set.
+/// Animated thumbnails can be set for animated sticker sets only.
+/// Video thumbnails can be set only for video sticker sets only.
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SetStickerSetThumb {
+    /// Sticker set name
+    pub name: String,
+
+    /// User identifier of the sticker set owner
+    pub user_id: i64,
+
+    /// A **PNG** image with the thumbnail, must be up to 128 kilobytes in size and have width and height exactly 100px,
+    /// or a **TGS** animation with the thumbnail up to 32 kilobytes in size,
+    /// or a **WEBM** video with the thumbnail up to 32 kilobytes in size.
+    /// Pass a *file_id* as a String to send a file that already exists on the Telegram servers,
+    /// pass an HTTP URL as a String for Telegram to get a file from the Internet,
+    /// or upload a new one using multipart/form-data.
+    /// Animated sticker set thumbnails can't be uploaded via HTTP URL.
+    /// If omitted, then the thumbnail is dropped and the first sticker is used as the thumbnail.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<InputFile>,
+}
+
+impl Call for SetStickerSetThumb {
+    type Response = bool;
+    const NAME: &'static str = "setStickerSetThumb";
+
+    fn attachments(&self) -> Vec<Attachment<'_>> {
+        self.thumb
+            .iter()
+            .filter_map(InputFile::attachment)
+            .collect()
+    }
+}
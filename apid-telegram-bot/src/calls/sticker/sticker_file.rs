@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{InputFile, StickerFormat};
+
+/// The sticker file added to a sticker set, in one of the sticker formats.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StickerFile {
+    /// **PNG** image with the sticker, must be up to 512 kilobytes in size, dimensions must not exceed 512px,
+    /// and either width or height must be exactly 512px.
+    /// Pass a *file_id* as a String to send a file that already exists on the Telegram servers,
+    /// pass an HTTP URL as a String for Telegram to get a file from the Internet,
+    /// or upload a new one using multipart/form-data.
+    PngSticker(InputFile),
+
+    /// **TGS** animation with the sticker, uploaded using multipart/form-data.
+    /// See <https://core.telegram.org/stickers#animated-sticker-requirements> for technical requirements
+    TgsSticker(InputFile),
+
+    /// **WEBM** video with the sticker, uploaded using multipart/form-data.
+    /// See <https://core.telegram.org/stickers#video-sticker-requirements> for technical requirements
+    WebmSticker(InputFile),
+}
+
+impl StickerFile {
+    /// The format of the sticker.
+    pub fn format(&self) -> StickerFormat {
+        match self {
+            StickerFile::PngSticker(_) => StickerFormat::Static,
+            StickerFile::TgsSticker(_) => StickerFormat::Animated,
+            StickerFile::WebmSticker(_) => StickerFormat::Video,
+        }
+    }
+
+    /// The file of the sticker.
+    pub fn file(&self) -> &InputFile {
+        match self {
+            StickerFile::PngSticker(file)
+            | StickerFile::TgsSticker(file)
+            | StickerFile::WebmSticker(file) => file,
+        }
+    }
+}
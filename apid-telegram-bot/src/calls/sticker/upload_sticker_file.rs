@@ -0,0 +1,25 @@
+use apid::{Attachment, Call};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{File, InputFile};
+
+/// Use this method to upload a .PNG file with a sticker for later use in *createNewStickerSet* and *addStickerToSet* methods (can be used multiple times).
+/// Returns the uploaded [`File`] on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct UploadStickerFile {
+    /// User identifier of sticker file owner
+    pub user_id: i64,
+
+    /// **PNG** image with the sticker, must be up to 512 kilobytes in size, dimensions must not exceed 512px,
+    /// and either width or height must be exactly 512px.
+    pub png_sticker: InputFile,
+}
+
+impl Call for UploadStickerFile {
+    type Response = File;
+    const NAME: &'static str = "uploadStickerFile";
+
+    fn attachments(&self) -> Vec<Attachment<'_>> {
+        self.png_sticker.attachment().into_iter().collect()
+    }
+}
@@ -0,0 +1,16 @@
+use apid::Call;
+use serde::{Deserialize, Serialize};
+
+/// Use this method to remove webhook integration if you decide to switch back to [`GetUpdates`](crate::calls::GetUpdates).
+/// Returns *True* on success.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeleteWebhook {
+    /// Pass *True* to drop all pending updates
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub drop_pending_updates: bool,
+}
+
+impl Call for DeleteWebhook {
+    type Response = bool;
+    const NAME: &'static str = "deleteWebhook";
+}
@@ -41,4 +41,5 @@ pub struct GetUpdates {
 
 impl Call for GetUpdates {
     type Response = Vec<Update>;
+    const NAME: &'static str = "getUpdates";
 }
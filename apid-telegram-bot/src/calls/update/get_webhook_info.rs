@@ -12,4 +12,5 @@ pub struct GetWebhookInfo {}
 
 impl Call for GetWebhookInfo {
     type Response = WebhookInfo;
+    const NAME: &'static str = "getWebhookInfo";
 }
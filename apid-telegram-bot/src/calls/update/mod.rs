@@ -1,4 +1,9 @@
+pub use delete_webhook::*;
 pub use get_updates::*;
+pub use get_webhook_info::*;
+pub use set_webhook::*;
 
+mod delete_webhook;
 mod get_updates;
 mod get_webhook_info;
+mod set_webhook;
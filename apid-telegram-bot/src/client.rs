@@ -0,0 +1,68 @@
+use apid::{Api, Call, HttpMethod, HttpRequest, HttpResponse};
+use serde::Serialize;
+
+use crate::{BotApi, Error};
+
+/// An asynchronous client sending [`Call`]s to the Bot API with [`reqwest`].
+#[derive(Debug, Clone)]
+pub struct Client {
+    http: reqwest::Client,
+    api: BotApi,
+}
+
+impl Client {
+    /// Creates a client for the bot with the given token on the cloud Bot API server.
+    pub fn new(token: impl Into<String>) -> Self {
+        Client::from_parts(reqwest::Client::new(), BotApi::new(token))
+    }
+
+    /// Creates a client for the bot with the given token on the Bot API server at `base_url`.
+    pub fn with_base_url(base_url: impl Into<String>, token: impl Into<String>) -> Self {
+        Client::from_parts(
+            reqwest::Client::new(),
+            BotApi::with_base_url(base_url, token),
+        )
+    }
+
+    /// Creates a client from a preconfigured [`reqwest::Client`] and the Bot API server to call.
+    pub fn from_parts(http: reqwest::Client, api: BotApi) -> Self {
+        Client { http, api }
+    }
+
+    /// The Bot API server the client sends its calls to.
+    pub fn api(&self) -> &BotApi {
+        &self.api
+    }
+
+    /// Creates a [`Polling`](crate::Polling) stream receiving updates through this client.
+    #[cfg(feature = "polling")]
+    pub fn polling(&self) -> crate::Polling<Client> {
+        crate::Polling::new(self.api.clone(), self.clone())
+    }
+
+    /// Sends the call to the Bot API and returns its result.
+    pub async fn call<C: Call + Serialize>(&self, call: &C) -> Result<C::Response, Error> {
+        let request = self.api.request(call)?;
+        let response = self.send(request).await?;
+        self.api.decode::<C>(response)
+    }
+
+    /// Sends a request built by [`BotApi::request`] and returns the raw response.
+    pub async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        let method = match request.method {
+            HttpMethod::Get => reqwest::Method::GET,
+            HttpMethod::Post => reqwest::Method::POST,
+            HttpMethod::Put => reqwest::Method::PUT,
+            HttpMethod::Patch => reqwest::Method::PATCH,
+            HttpMethod::Delete => reqwest::Method::DELETE,
+        };
+        let mut builder = self.http.request(method, request.url);
+        for (name, value) in request.headers {
+            builder = builder.header(name, value);
+        }
+        let response = builder.body(request.body).send().await?;
+        let status = response.status().as_u16();
+        let body = response.bytes().await?.to_vec();
+        Ok(HttpResponse { status, body })
+    }
+}
@@ -0,0 +1,215 @@
+use std::fmt;
+
+use crate::types::ResponseParameters;
+
+/// An error occurred while calling the Bot API.
+#[derive(Debug)]
+pub enum Error {
+    /// The Bot API returned an error
+    Api(ApiError),
+
+    /// The request or the response couldn't be (de)serialized
+    Json(serde_json::Error),
+
+    /// The server responded with an unsuccessful status code and a body that isn't a Bot API response,
+    /// e.g. the error page of a proxy in front of the Bot API server
+    Status(u16),
+
+    /// The request couldn't be sent or the response couldn't be received.
+    /// The URL is stripped from the error, since it contains the bot token.
+    #[cfg(feature = "reqwest")]
+    Http(reqwest::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Api(error) => write!(f, "Bot API error: {}", error),
+            Error::Json(error) => write!(f, "JSON error: {}", error),
+            Error::Status(status) => write!(f, "unexpected HTTP status {}", status),
+            #[cfg(feature = "reqwest")]
+            Error::Http(error) => write!(f, "HTTP error: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Api(error) => Some(error),
+            Error::Json(error) => Some(error),
+            Error::Status(_) => None,
+            #[cfg(feature = "reqwest")]
+            Error::Http(error) => Some(error),
+        }
+    }
+}
+
+impl From<ApiError> for Error {
+    fn from(error: ApiError) -> Self {
+        Error::Api(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::Json(error)
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Error::Http(error.without_url())
+    }
+}
+
+/// An error returned by the Bot API, classified by its error code.
+#[derive(Debug, PartialEq)]
+pub enum ApiError {
+    /// The request was malformed or refers to something that doesn't exist (error code 400)
+    BadRequest {
+        /// A human-readable description of the error
+        description: String,
+    },
+
+    /// The group has been migrated to a supergroup,
+    /// the request should be repeated with the new chat identifier (error code 400)
+    MigrateToChat {
+        /// A human-readable description of the error
+        description: String,
+
+        /// The identifier of the supergroup the group has been migrated to
+        migrate_to_chat_id: i64,
+    },
+
+    /// The bot token is invalid or has been revoked (error code 401)
+    Unauthorized {
+        /// A human-readable description of the error
+        description: String,
+    },
+
+    /// The bot isn't allowed to perform the request,
+    /// e.g. it was blocked by the user or kicked from the chat (error code 403)
+    Forbidden {
+        /// A human-readable description of the error
+        description: String,
+    },
+
+    /// The request conflicts with another one,
+    /// e.g. getUpdates is called while a webhook is set or by another instance of the bot (error code 409)
+    Conflict {
+        /// A human-readable description of the error
+        description: String,
+    },
+
+    /// Flood control was exceeded (error code 429)
+    TooManyRequests {
+        /// A human-readable description of the error
+        description: String,
+
+        /// The number of seconds left to wait before the request can be repeated
+        retry_after: Option<i32>,
+    },
+
+    /// Any other error
+    Other {
+        /// The error code
+        error_code: i32,
+
+        /// A human-readable description of the error
+        description: String,
+
+        /// Information which can help to automatically handle the error
+        parameters: Option<ResponseParameters>,
+    },
+}
+
+impl ApiError {
+    /// Classifies an error from the parts of an unsuccessful [`ApiResponse`](crate::types::ApiResponse).
+    pub fn new(
+        error_code: i32,
+        description: String,
+        parameters: Option<ResponseParameters>,
+    ) -> Self {
+        let migrate_to_chat_id = parameters.as_ref().and_then(|p| p.migrate_to_chat_id);
+        let retry_after = parameters.as_ref().and_then(|p| p.retry_after);
+        match (error_code, migrate_to_chat_id) {
+            (400, Some(migrate_to_chat_id)) => ApiError::MigrateToChat {
+                description,
+                migrate_to_chat_id,
+            },
+            (400, None) => ApiError::BadRequest { description },
+            (401, _) => ApiError::Unauthorized { description },
+            (403, _) => ApiError::Forbidden { description },
+            (409, _) => ApiError::Conflict { description },
+            (429, _) => ApiError::TooManyRequests {
+                description,
+                retry_after,
+            },
+            _ => ApiError::Other {
+                error_code,
+                description,
+                parameters,
+            },
+        }
+    }
+
+    /// The error code of the error
+    pub fn error_code(&self) -> i32 {
+        match self {
+            ApiError::BadRequest { .. } | ApiError::MigrateToChat { .. } => 400,
+            ApiError::Unauthorized { .. } => 401,
+            ApiError::Forbidden { .. } => 403,
+            ApiError::Conflict { .. } => 409,
+            ApiError::TooManyRequests { .. } => 429,
+            ApiError::Other { error_code, .. } => *error_code,
+        }
+    }
+
+    /// A human-readable description of the error
+    pub fn description(&self) -> &str {
+        match self {
+            ApiError::BadRequest { description }
+            | ApiError::MigrateToChat { description, .. }
+            | ApiError::Unauthorized { description }
+            | ApiError::Forbidden { description }
+            | ApiError::Conflict { description }
+            | ApiError::TooManyRequests { description, .. }
+            | ApiError::Other { description, .. } => description,
+        }
+    }
+
+    /// In case of exceeding flood control, the number of seconds left to wait before the request can be repeated
+    pub fn retry_after(&self) -> Option<i32> {
+        match self {
+            ApiError::TooManyRequests { retry_after, .. } => *retry_after,
+            ApiError::Other { parameters, .. } => parameters.as_ref()?.retry_after,
+            _ => None,
+        }
+    }
+
+    /// In case of a group migration, the identifier of the supergroup the group has been migrated to
+    pub fn migrate_to_chat_id(&self) -> Option<i64> {
+        match self {
+            ApiError::MigrateToChat {
+                migrate_to_chat_id, ..
+            } => Some(*migrate_to_chat_id),
+            ApiError::Other { parameters, .. } => parameters.as_ref()?.migrate_to_chat_id,
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (error code {})",
+            self.description(),
+            self.error_code()
+        )
+    }
+}
+
+impl std::error::Error for ApiError {}
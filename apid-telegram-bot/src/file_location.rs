@@ -0,0 +1,12 @@
+use std::path::PathBuf;
+
+/// Where the contents of a [`File`](crate::types::File) can be read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileLocation {
+    /// The URL to download the file from
+    Url(String),
+
+    /// The path of the file on the machine running a local Bot API server,
+    /// which returns absolute paths instead of downloadable ones
+    Local(PathBuf),
+}
@@ -10,10 +10,40 @@
 //!
 //! Warning:
 //! Currently, the crate  has lack of types and very WIP.
+//!
+//! ## Features
+//!
+//! - `reqwest`: an asynchronous `Client` sending calls with [reqwest](https://docs.rs/reqwest).
+//! - `webhook`: a `WebhookHandler` receiving updates sent to a webhook, independent of any web framework.
+//! - `hyper`: receiving updates from an `http::Request`, as served by [hyper](https://docs.rs/hyper).
+//! - `axum`: the `WebhookUpdate` extractor for [axum](https://docs.rs/axum).
+//! - `polling`: a `Polling` stream receiving updates with long polling.
+//! - `passport`: the `passport` module decrypting Telegram Passport data.
 
 #![warn(missing_docs)]
 
+pub use bot_api::*;
+#[cfg(feature = "reqwest")]
+pub use client::*;
+pub use error::*;
+pub use file_location::*;
+#[cfg(feature = "polling")]
+pub use polling::*;
+#[cfg(feature = "webhook")]
+pub use webhook::*;
+
 pub mod types;
 
+mod bot_api;
 pub mod calls;
+#[cfg(feature = "reqwest")]
+mod client;
+mod error;
+mod file_location;
+#[cfg(feature = "passport")]
+pub mod passport;
+#[cfg(feature = "polling")]
+mod polling;
 mod util;
+#[cfg(feature = "webhook")]
+mod webhook;
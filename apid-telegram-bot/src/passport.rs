@@ -0,0 +1,154 @@
+//! Decryption of Telegram Passport data.
+//!
+//! The bot receives [`PassportData`](crate::types::PassportData) whose credentials are encrypted with the bot's public RSA key.
+//! Decrypting them with [`PassportKey::decrypt_credentials`] yields the secrets
+//! to decrypt each element's data with [`decrypt_data`] and each downloaded file with [`decrypt_file`].
+//!
+//! See the [Telegram Passport Documentation](https://core.telegram.org/passport#decrypting-data) for the details.
+
+use std::fmt;
+
+use aes::cipher::{block_padding::NoPadding, BlockDecryptMut, KeyIvInit};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rsa::{pkcs1::DecodeRsaPrivateKey, pkcs8::DecodePrivateKey, Oaep, RsaPrivateKey};
+use serde::de::DeserializeOwned;
+use sha2::{Digest, Sha256, Sha512};
+
+use crate::types::{Credentials, DataCredentials, EncryptedCredentials, FileCredentials};
+
+/// The bot's private RSA key, used to decrypt [`EncryptedCredentials`].
+pub struct PassportKey(RsaPrivateKey);
+
+impl PassportKey {
+    /// Parses a PEM-encoded private key, either in PKCS#1 (`BEGIN RSA PRIVATE KEY`) or PKCS#8 (`BEGIN PRIVATE KEY`) form.
+    pub fn from_pem(pem: &str) -> Result<Self, PassportError> {
+        RsaPrivateKey::from_pkcs1_pem(pem)
+            .or_else(|_| RsaPrivateKey::from_pkcs8_pem(pem))
+            .map(PassportKey)
+            .map_err(|_| PassportError::InvalidKey)
+    }
+
+    /// Decrypts and authenticates the credentials shared along with the Telegram Passport data.
+    pub fn decrypt_credentials(
+        &self,
+        credentials: &EncryptedCredentials,
+    ) -> Result<Credentials, PassportError> {
+        let secret = self
+            .0
+            .decrypt(Oaep::new::<sha1::Sha1>(), &decode(&credentials.secret)?)
+            .map_err(PassportError::Rsa)?;
+        let data = decrypt(
+            &decode(&credentials.data)?,
+            &secret,
+            &decode(&credentials.hash)?,
+        )?;
+        serde_json::from_slice(&data).map_err(PassportError::Json)
+    }
+}
+
+impl From<RsaPrivateKey> for PassportKey {
+    fn from(key: RsaPrivateKey) -> Self {
+        PassportKey(key)
+    }
+}
+
+impl fmt::Debug for PassportKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PassportKey(..)")
+    }
+}
+
+/// Decrypts and authenticates the base64-encoded *data* field of an [`EncryptedPassportElement`](crate::types::EncryptedPassportElement),
+/// deserializing it into e.g. [`PersonalDetails`](crate::types::PersonalDetails).
+pub fn decrypt_data<T: DeserializeOwned>(
+    data: &str,
+    credentials: &DataCredentials,
+) -> Result<T, PassportError> {
+    let data = decrypt(
+        &decode(data)?,
+        &decode(&credentials.secret)?,
+        &decode(&credentials.data_hash)?,
+    )?;
+    serde_json::from_slice(&data).map_err(PassportError::Json)
+}
+
+/// Decrypts and authenticates the contents of a downloaded [`PassportFile`](crate::types::PassportFile).
+pub fn decrypt_file(data: &[u8], credentials: &FileCredentials) -> Result<Vec<u8>, PassportError> {
+    decrypt(
+        data,
+        &decode(&credentials.secret)?,
+        &decode(&credentials.file_hash)?,
+    )
+}
+
+fn decode(value: &str) -> Result<Vec<u8>, PassportError> {
+    STANDARD.decode(value).map_err(PassportError::Base64)
+}
+
+fn decrypt(data: &[u8], secret: &[u8], hash: &[u8]) -> Result<Vec<u8>, PassportError> {
+    let secret_hash = Sha512::new()
+        .chain_update(secret)
+        .chain_update(hash)
+        .finalize();
+    let (key, iv) = secret_hash[..48].split_at(32);
+
+    let decrypted = cbc::Decryptor::<aes::Aes256>::new(key.into(), iv.into())
+        .decrypt_padded_vec_mut::<NoPadding>(data)
+        .map_err(|_| PassportError::InvalidData)?;
+
+    if Sha256::digest(&decrypted).as_slice() != hash {
+        return Err(PassportError::HashMismatch);
+    }
+
+    let padding = decrypted.first().copied().unwrap_or(0) as usize;
+    if padding == 0 || padding > decrypted.len() {
+        return Err(PassportError::InvalidData);
+    }
+    Ok(decrypted[padding..].to_vec())
+}
+
+/// An error occurred while decrypting Telegram Passport data.
+#[derive(Debug)]
+pub enum PassportError {
+    /// The private key is neither a PKCS#1 nor a PKCS#8 PEM-encoded RSA key.
+    InvalidKey,
+
+    /// The credentials secret could not be decrypted with the private key.
+    Rsa(rsa::Error),
+
+    /// A value was not valid base64.
+    Base64(base64::DecodeError),
+
+    /// The encrypted data is not block-aligned or its padding is malformed.
+    InvalidData,
+
+    /// The decrypted data does not match its hash.
+    HashMismatch,
+
+    /// The decrypted data is not the expected JSON.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for PassportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PassportError::InvalidKey => f.write_str("invalid RSA private key"),
+            PassportError::Rsa(error) => write!(f, "failed to decrypt the secret: {}", error),
+            PassportError::Base64(error) => write!(f, "invalid base64: {}", error),
+            PassportError::InvalidData => f.write_str("malformed encrypted data"),
+            PassportError::HashMismatch => f.write_str("decrypted data does not match its hash"),
+            PassportError::Json(error) => write!(f, "invalid decrypted data: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for PassportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PassportError::Rsa(error) => Some(error),
+            PassportError::Base64(error) => Some(error),
+            PassportError::Json(error) => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,8 @@
+pub use polling::*;
+pub use shutdown_handle::*;
+pub use transport::*;
+
+#[allow(clippy::module_inception)]
+mod polling;
+mod shutdown_handle;
+mod transport;
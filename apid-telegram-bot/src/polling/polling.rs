@@ -0,0 +1,214 @@
+use std::{
+    collections::VecDeque,
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+    time::Duration,
+};
+
+use apid::Api;
+use futures_core::Stream;
+use tokio::time::Sleep;
+
+use crate::{
+    calls::GetUpdates,
+    types::{Update, UpdateKind},
+    ApiError, BotApi, Error, ShutdownHandle, Transport, TransportFuture,
+};
+
+type ErrorHandler = Box<dyn FnMut(&Error) + Send>;
+
+/// A [`Stream`] of [`Update`]s received with long polling by calling [`GetUpdates`] repeatedly.
+///
+/// The *offset* of each call is taken from the last update returned by the stream,
+/// so an update is confirmed as soon as the stream is polled for the next one.
+/// Failed calls are retried after a delay doubling from [`Polling::backoff`]'s minimum up to its maximum,
+/// or after *retry_after* seconds when the Bot API asks for it.
+/// This also covers the conflict (error code 409) returned while a webhook is set or another instance of the bot is polling.
+/// The stream ends instead if the token is rejected (error code 401), since retrying can't succeed.
+///
+/// Once the stream has been stopped with a [`ShutdownHandle`],
+/// [`Polling::shutdown`] confirms the updates returned so far, so they aren't received again on the next start.
+pub struct Polling<T> {
+    api: BotApi,
+    transport: T,
+    call: GetUpdates,
+    offset: Option<i32>,
+    acknowledged: Option<i32>,
+    updates: VecDeque<Update>,
+    request: Option<TransportFuture>,
+    sleep: Option<Pin<Box<Sleep>>>,
+    min_backoff: Duration,
+    max_backoff: Duration,
+    backoff: Option<Duration>,
+    on_error: Option<ErrorHandler>,
+    shutdown: ShutdownHandle,
+    unauthorized: bool,
+}
+
+impl<T: Transport> Polling<T> {
+    /// Creates a stream polling the given Bot API server through the transport.
+    /// Polls with a *timeout* of 30 seconds and retries after 1 to 60 seconds by default.
+    pub fn new(api: BotApi, transport: T) -> Self {
+        Polling {
+            api,
+            transport,
+            call: GetUpdates {
+                offset: None,
+                limit: None,
+                timeout: Some(30),
+                allowed_updates: None,
+            },
+            offset: None,
+            acknowledged: None,
+            updates: VecDeque::new(),
+            request: None,
+            sleep: None,
+            min_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            backoff: None,
+            on_error: None,
+            shutdown: ShutdownHandle::default(),
+            unauthorized: false,
+        }
+    }
+
+    /// Starts from the update with the given identifier, see [`GetUpdates::offset`].
+    pub fn offset(mut self, offset: i32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Limits the number of updates retrieved per call, see [`GetUpdates::limit`].
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.call.limit = Some(limit);
+        self
+    }
+
+    /// Sets the timeout in seconds for long polling, see [`GetUpdates::timeout`].
+    pub fn timeout(mut self, timeout: i32) -> Self {
+        self.call.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the update types to receive, see [`GetUpdates::allowed_updates`].
+    pub fn allowed_updates(mut self, allowed_updates: Vec<UpdateKind>) -> Self {
+        self.call.allowed_updates = Some(allowed_updates);
+        self
+    }
+
+    /// Sets the delay before retrying the first failed call and the maximum it doubles up to.
+    pub fn backoff(mut self, min: Duration, max: Duration) -> Self {
+        self.min_backoff = min;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Calls `on_error` with every error before the failed call is retried or the stream ends, e.g. for logging.
+    pub fn on_error(mut self, on_error: impl FnMut(&Error) + Send + 'static) -> Self {
+        self.on_error = Some(Box::new(on_error));
+        self
+    }
+
+    /// A handle ending the stream.
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown.clone()
+    }
+
+    /// Confirms the updates returned by the stream that haven't been confirmed by a following call yet.
+    /// Updates received but not returned yet are left to be received again.
+    pub async fn shutdown(self) -> Result<(), Error> {
+        if self.offset.is_none() || self.offset == self.acknowledged {
+            return Ok(());
+        }
+        let request = self.api.request(&GetUpdates {
+            offset: self.offset,
+            limit: Some(1),
+            timeout: Some(0),
+            allowed_updates: None,
+        })?;
+        let response = self.transport.send(request).await?;
+        self.api.decode::<GetUpdates>(response)?;
+        Ok(())
+    }
+
+    fn fail(&mut self, error: Error) {
+        if let Some(on_error) = &mut self.on_error {
+            on_error(&error);
+        }
+        if let Error::Api(ApiError::Unauthorized { .. }) = error {
+            self.unauthorized = true;
+            return;
+        }
+        let delay = match &error {
+            Error::Api(error) => error.retry_after(),
+            _ => None,
+        };
+        let delay = match delay {
+            Some(retry_after) => Duration::from_secs(retry_after.max(0) as u64),
+            None => {
+                let backoff = self.backoff.unwrap_or(self.min_backoff);
+                self.backoff = Some(backoff.saturating_mul(2).min(self.max_backoff));
+                backoff
+            }
+        };
+        self.sleep = Some(Box::pin(tokio::time::sleep(delay)));
+    }
+}
+
+impl<T: Transport + Unpin> Stream for Polling<T> {
+    type Item = Update;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Update>> {
+        let this = self.get_mut();
+        this.shutdown.register(cx.waker());
+        loop {
+            if this.shutdown.is_shutdown() || this.unauthorized {
+                this.request = None;
+                this.sleep = None;
+                return Poll::Ready(None);
+            }
+            if let Some(update) = this.updates.pop_front() {
+                this.offset = Some(update.update_id + 1);
+                return Poll::Ready(Some(update));
+            }
+            if let Some(sleep) = &mut this.sleep {
+                ready!(sleep.as_mut().poll(cx));
+                this.sleep = None;
+            }
+            match &mut this.request {
+                None => {
+                    this.call.offset = this.offset;
+                    match this.api.request(&this.call) {
+                        Ok(request) => this.request = Some(this.transport.send(request)),
+                        Err(error) => this.fail(error.into()),
+                    }
+                }
+                Some(request) => {
+                    let response = ready!(request.as_mut().poll(cx));
+                    this.request = None;
+                    match response.and_then(|response| this.api.decode::<GetUpdates>(response)) {
+                        Ok(updates) => {
+                            this.acknowledged = this.call.offset;
+                            this.backoff = None;
+                            this.updates.extend(updates);
+                        }
+                        Err(error) => this.fail(error),
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Polling<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Polling")
+            .field("transport", &self.transport)
+            .field("call", &self.call)
+            .field("offset", &self.offset)
+            .field("updates", &self.updates.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,36 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::Waker,
+};
+
+/// Stops a [`Polling`](crate::Polling) stream from another task, e.g. on Ctrl-C.
+#[derive(Debug, Clone, Default)]
+pub struct ShutdownHandle(Arc<Shutdown>);
+
+#[derive(Debug, Default)]
+struct Shutdown {
+    requested: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl ShutdownHandle {
+    /// Ends the stream: it returns `None` the next time it is polled, and any request in flight is dropped.
+    pub fn shutdown(&self) {
+        self.0.requested.store(true, Ordering::SeqCst);
+        if let Some(waker) = self.0.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+
+    /// Whether [`ShutdownHandle::shutdown`] has been called.
+    pub fn is_shutdown(&self) -> bool {
+        self.0.requested.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn register(&self, waker: &Waker) {
+        *self.0.waker.lock().unwrap() = Some(waker.clone());
+    }
+}
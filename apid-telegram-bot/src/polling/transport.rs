@@ -0,0 +1,22 @@
+use std::{future::Future, pin::Pin};
+
+use apid::{HttpRequest, HttpResponse};
+
+use crate::Error;
+
+/// The future returned by [`Transport::send`].
+pub type TransportFuture = Pin<Box<dyn Future<Output = Result<HttpResponse, Error>> + Send>>;
+
+/// Sends the requests built by [`Api::request`](apid::Api::request) for a [`Polling`](crate::Polling) stream.
+pub trait Transport {
+    /// Sends the request and returns the raw response.
+    fn send(&self, request: HttpRequest) -> TransportFuture;
+}
+
+#[cfg(feature = "reqwest")]
+impl Transport for crate::Client {
+    fn send(&self, request: HttpRequest) -> TransportFuture {
+        let client = self.clone();
+        Box::pin(async move { client.send(request).await })
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{types::ResponseParameters, ApiError};
+
+/// The envelope the Bot API wraps around the result of every [`Call`](apid::Call).
+///
+/// If *ok* equals *True*, the request was successful and the result of the query can be found in the *result* field.
+/// In case of an unsuccessful request, *ok* equals *False* and the error is explained in the *description*.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApiResponse<T> {
+    /// *True*, if the request was successful
+    pub ok: bool,
+
+    /// The result of the request, present on success
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<T>,
+
+    /// A human-readable description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The error code, present on failure.
+    /// Its contents are subject to change in the future.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<i32>,
+
+    /// Information which can help to automatically handle the error
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<ResponseParameters>,
+}
+
+impl<T> ApiResponse<T> {
+    /// Converts the envelope into the result of the request, classifying the error on failure.
+    pub fn into_result(self) -> Result<T, ApiError> {
+        match self.result {
+            Some(result) if self.ok => Ok(result),
+            _ => Err(ApiError::new(
+                self.error_code.unwrap_or_default(),
+                self.description.unwrap_or_default(),
+                self.parameters,
+            )),
+        }
+    }
+}
@@ -1,5 +1,7 @@
 pub use bot_command::*;
 pub use bot_command_scope::*;
+pub use menu_button::*;
 
 mod bot_command;
 mod bot_command_scope;
+mod menu_button;
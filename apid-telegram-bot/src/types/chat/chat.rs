@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{ChatKind, ChatLocation, ChatPhoto, Message};
+use crate::types::{ChatKind, ChatLocation, ChatPermissions, ChatPhoto, Message};
 
 /// This object represents a chat.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -113,6 +113,3 @@ pub struct ChatUser {
     /// Returned only in [getChat](https://core.telegram.org/bots/api#getchat).
     pub bio: Option<String>,
 }
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct ChatPermissions {}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// The action broadcast to the user by [`SendChatAction`](crate::calls::SendChatAction).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatAction {
+    /// For [text messages](crate::calls::SendMessage)
+    Typing,
+
+    /// For [photos](crate::calls::SendPhoto)
+    UploadPhoto,
+
+    /// For [videos](crate::calls::SendVideo) being recorded
+    RecordVideo,
+
+    /// For [videos](crate::calls::SendVideo) being uploaded
+    UploadVideo,
+
+    /// For [voice notes](crate::calls::SendVoice) being recorded
+    RecordVoice,
+
+    /// For [voice notes](crate::calls::SendVoice) being uploaded
+    UploadVoice,
+
+    /// For [general files](crate::calls::SendDocument)
+    UploadDocument,
+
+    /// For [stickers](crate::calls::SendSticker)
+    ChooseSticker,
+
+    /// For [location data](crate::calls::SendLocation)
+    FindLocation,
+
+    /// For [video notes](crate::calls::SendVideoNote) being recorded
+    RecordVideoNote,
+
+    /// For [video notes](crate::calls::SendVideoNote) being uploaded
+    UploadVideoNote,
+}
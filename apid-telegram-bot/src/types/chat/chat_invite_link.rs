@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::User;
+
+/// Represents an invite link for a chat.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChatInviteLink {
+    /// The invite link.
+    /// If the link was created by another chat administrator, then the second part of the link will be replaced with “…”.
+    pub invite_link: String,
+
+    /// Creator of the link
+    pub creator: User,
+
+    /// *True*, if users joining the chat via the link need to be approved by chat administrators
+    pub creates_join_request: bool,
+
+    /// *True*, if the link is primary
+    pub is_primary: bool,
+
+    /// *True*, if the link is revoked
+    pub is_revoked: bool,
+
+    /// Invite link name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Point in time (Unix timestamp) when the link will expire or has been expired
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<i32>,
+
+    /// The maximum number of users that can be members of the chat simultaneously after joining the chat via this invite link; 1-99999
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<i32>,
+
+    /// Number of pending join requests created using this link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_join_request_count: Option<i32>,
+}
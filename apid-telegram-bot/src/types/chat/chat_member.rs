@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::User;
+
+/// This object contains information about one member of a chat.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "status")]
+pub enum ChatMember {
+    /// Represents a [chat member](https://core.telegram.org/bots/api#chatmember) that owns the chat and has all administrator privileges.
+    #[serde(rename = "creator")]
+    Owner {
+        /// Information about the user
+        user: User,
+
+        /// *True*, if the user's presence in the chat is hidden
+        is_anonymous: bool,
+
+        /// Custom title for this user
+        #[serde(skip_serializing_if = "Option::is_none")]
+        custom_title: Option<String>,
+    },
+
+    /// Represents a [chat member](https://core.telegram.org/bots/api#chatmember) that has some additional privileges.
+    Administrator {
+        /// Information about the user
+        user: User,
+
+        /// *True*, if the bot is allowed to edit administrator privileges of that user
+        can_be_edited: bool,
+
+        /// *True*, if the user's presence in the chat is hidden
+        is_anonymous: bool,
+
+        /// *True*, if the administrator can access the chat event log, chat statistics, message statistics in channels, see channel members, see anonymous administrators in supergroups and ignore slow mode.
+        /// Implied by any other administrator privilege
+        can_manage_chat: bool,
+
+        /// *True*, if the administrator can delete messages of other users
+        can_delete_messages: bool,
+
+        /// *True*, if the administrator can manage video chats
+        can_manage_video_chats: bool,
+
+        /// *True*, if the administrator can restrict, ban or unban chat members
+        can_restrict_members: bool,
+
+        /// *True*, if the administrator can add new administrators with a subset of their own privileges or demote administrators that he has promoted,
+        /// directly or indirectly (promoted by administrators that were appointed by the user)
+        can_promote_members: bool,
+
+        /// *True*, if the user is allowed to change the chat title, photo and other settings
+        can_change_info: bool,
+
+        /// *True*, if the user is allowed to invite new users to the chat
+        can_invite_users: bool,
+
+        /// *True*, if the administrator can post in the channel; channels only
+        #[serde(default, skip_serializing_if = "crate::util::is_false")]
+        can_post_messages: bool,
+
+        /// *True*, if the administrator can edit messages of other users and can pin messages; channels only
+        #[serde(default, skip_serializing_if = "crate::util::is_false")]
+        can_edit_messages: bool,
+
+        /// *True*, if the user is allowed to pin messages; groups and supergroups only
+        #[serde(default, skip_serializing_if = "crate::util::is_false")]
+        can_pin_messages: bool,
+
+        /// Custom title for this user
+        #[serde(skip_serializing_if = "Option::is_none")]
+        custom_title: Option<String>,
+    },
+
+    /// Represents a [chat member](https://core.telegram.org/bots/api#chatmember) that has no additional privileges or restrictions.
+    Member {
+        /// Information about the user
+        user: User,
+    },
+
+    /// Represents a [chat member](https://core.telegram.org/bots/api#chatmember) that is under certain restrictions in the chat.
+    /// Supergroups only.
+    Restricted {
+        /// Information about the user
+        user: User,
+
+        /// *True*, if the user is a member of the chat at the moment of the request
+        is_member: bool,
+
+        /// *True*, if the user is allowed to change the chat title, photo and other settings
+        can_change_info: bool,
+
+        /// *True*, if the user is allowed to invite new users to the chat
+        can_invite_users: bool,
+
+        /// *True*, if the user is allowed to pin messages
+        can_pin_messages: bool,
+
+        /// *True*, if the user is allowed to send text messages, contacts, locations and venues
+        can_send_messages: bool,
+
+        /// *True*, if the user is allowed to send audios, documents, photos, videos, video notes and voice notes
+        can_send_media_messages: bool,
+
+        /// *True*, if the user is allowed to send polls
+        can_send_polls: bool,
+
+        /// *True*, if the user is allowed to send animations, games, stickers and use inline bots
+        can_send_other_messages: bool,
+
+        /// *True*, if the user is allowed to add web page previews to their messages
+        can_add_web_page_previews: bool,
+
+        /// Date when restrictions will be lifted for this user; unix time.
+        /// If 0, then the user is restricted forever
+        until_date: i32,
+    },
+
+    /// Represents a [chat member](https://core.telegram.org/bots/api#chatmember) that isn't currently a member of the chat, but may join it themselves.
+    Left {
+        /// Information about the user
+        user: User,
+    },
+
+    /// Represents a [chat member](https://core.telegram.org/bots/api#chatmember) that was banned in the chat and can't return to the chat or view chat messages.
+    #[serde(rename = "kicked")]
+    Banned {
+        /// Information about the user
+        user: User,
+
+        /// Date when restrictions will be lifted for this user; unix time.
+        /// If 0, then the user is banned forever
+        until_date: i32,
+    },
+}
+
+impl ChatMember {
+    /// Information about the user
+    pub fn user(&self) -> &User {
+        match self {
+            ChatMember::Owner { user, .. }
+            | ChatMember::Administrator { user, .. }
+            | ChatMember::Member { user }
+            | ChatMember::Restricted { user, .. }
+            | ChatMember::Left { user }
+            | ChatMember::Banned { user, .. } => user,
+        }
+    }
+
+    /// *True*, if the user is a member of the chat,
+    /// i.e. an owner, an administrator, a member or a restricted user who is still in the chat
+    pub fn is_present(&self) -> bool {
+        match self {
+            ChatMember::Owner { .. }
+            | ChatMember::Administrator { .. }
+            | ChatMember::Member { .. } => true,
+            ChatMember::Restricted { is_member, .. } => *is_member,
+            ChatMember::Left { .. } | ChatMember::Banned { .. } => false,
+        }
+    }
+
+    /// *True*, if the user is the owner or an administrator of the chat
+    pub fn is_privileged(&self) -> bool {
+        matches!(
+            self,
+            ChatMember::Owner { .. } | ChatMember::Administrator { .. }
+        )
+    }
+}
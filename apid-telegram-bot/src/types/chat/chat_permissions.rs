@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes actions that a non-administrator user is allowed to take in a chat.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChatPermissions {
+    /// *True*, if the user is allowed to send text messages, contacts, locations and venues
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_send_messages: bool,
+
+    /// *True*, if the user is allowed to send audios, documents, photos, videos, video notes and voice notes,
+    /// implies *can_send_messages*
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_send_media_messages: bool,
+
+    /// *True*, if the user is allowed to send polls, implies *can_send_messages*
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_send_polls: bool,
+
+    /// *True*, if the user is allowed to send animations, games, stickers and use inline bots,
+    /// implies *can_send_media_messages*
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_send_other_messages: bool,
+
+    /// *True*, if the user is allowed to add web page previews to their messages,
+    /// implies *can_send_media_messages*
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_add_web_page_previews: bool,
+
+    /// *True*, if the user is allowed to change the chat title, photo and other settings.
+    /// Ignored in public supergroups
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_change_info: bool,
+
+    /// *True*, if the user is allowed to invite new users to the chat
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_invite_users: bool,
+
+    /// *True*, if the user is allowed to pin messages.
+    /// Ignored in public supergroups
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub can_pin_messages: bool,
+}
@@ -1,11 +1,21 @@
 pub use chat::*;
+pub use chat_action::*;
+pub use chat_administrator_rights::*;
 pub use chat_id::*;
+pub use chat_invite_link::*;
 pub use chat_kind::*;
 pub use chat_location::*;
+pub use chat_member::*;
+pub use chat_permissions::*;
 pub use chat_photo::*;
 
 mod chat;
+mod chat_action;
+mod chat_administrator_rights;
 mod chat_id;
+mod chat_invite_link;
 mod chat_kind;
 mod chat_location;
+mod chat_member;
+mod chat_permissions;
 mod chat_photo;
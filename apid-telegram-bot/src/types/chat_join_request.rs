@@ -1,7 +1,24 @@
 use serde::{Deserialize, Serialize};
 
+use crate::types::{Chat, ChatInviteLink, User};
+
+/// Represents a join request sent to a chat.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct ChatJoinRequest {
-    // TODO:
-    __never_happen: String,
+    /// Chat to which the request was sent
+    pub chat: Chat,
+
+    /// User that sent the join request
+    pub from: User,
+
+    /// Date the request was sent in Unix time
+    pub date: i32,
+
+    /// Bio of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+
+    /// Chat invite link that was used by the user to send the join request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invite_link: Option<ChatInviteLink>,
 }
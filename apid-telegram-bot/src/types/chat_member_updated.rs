@@ -1,7 +1,72 @@
 use serde::{Deserialize, Serialize};
 
+use crate::types::{Chat, ChatInviteLink, ChatKind, ChatMember, User};
+
+/// This object represents changes in the status of a chat member.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct ChatMemberUpdated {
-    // TODO:
-    __never_happen: String,
+    /// Chat the user belongs to
+    pub chat: Chat,
+
+    /// Performer of the action, which resulted in the change
+    pub from: User,
+
+    /// Date the change was done in Unix time
+    pub date: i32,
+
+    /// Previous information about the chat member
+    pub old_chat_member: ChatMember,
+
+    /// New information about the chat member
+    pub new_chat_member: ChatMember,
+
+    /// Chat invite link, which was used by the user to join the chat; for joining by invite link events only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invite_link: Option<ChatInviteLink>,
+}
+
+/// Helpers telling the transition between the statuses of the chat member.
+///
+/// They look at the statuses only, not at who the chat member is.
+/// In *my_chat_member* updates the chat member is the bot itself,
+/// while in *chat_member* updates it can be any user.
+impl ChatMemberUpdated {
+    /// *True*, if the chat member was added to a group, a supergroup or a channel
+    pub fn was_added(&self) -> bool {
+        !self.is_private()
+            && !self.old_chat_member.is_present()
+            && self.new_chat_member.is_present()
+    }
+
+    /// *True*, if the chat member was banned, or removed by someone else, from a group, a supergroup or a channel.
+    /// Leaving the chat voluntarily doesn't count.
+    pub fn was_kicked(&self) -> bool {
+        !self.is_private()
+            && self.old_chat_member.is_present()
+            && !self.new_chat_member.is_present()
+            && (matches!(self.new_chat_member, ChatMember::Banned { .. })
+                || self.from.id != self.new_chat_member.user().id)
+    }
+
+    /// *True*, if the chat member was promoted to an administrator
+    pub fn was_promoted(&self) -> bool {
+        !self.old_chat_member.is_privileged() && self.new_chat_member.is_privileged()
+    }
+
+    /// *True*, if the bot was blocked by the user in a private chat.
+    /// Only the bot itself can be a member of a private chat, in *my_chat_member* updates.
+    pub fn was_blocked(&self) -> bool {
+        self.is_private() && matches!(self.new_chat_member, ChatMember::Banned { .. })
+    }
+
+    /// *True*, if the bot was unblocked by the user in a private chat
+    pub fn was_unblocked(&self) -> bool {
+        self.is_private()
+            && matches!(self.old_chat_member, ChatMember::Banned { .. })
+            && matches!(self.new_chat_member, ChatMember::Member { .. })
+    }
+
+    fn is_private(&self) -> bool {
+        matches!(self.chat.kind, ChatKind::Private)
+    }
 }
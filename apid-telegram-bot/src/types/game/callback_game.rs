@@ -3,4 +3,4 @@ use serde::{Deserialize, Serialize};
 /// A placeholder, currently holds no information.
 /// Use BotFather to set up your game.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct CallbackGame;
+pub struct CallbackGame {}
@@ -0,0 +1,159 @@
+use std::io::{self, Read};
+
+use apid::Attachment;
+use serde::{Deserialize, Serialize};
+
+/// This object represents the contents of a file to be sent.
+///
+/// There are three ways to send files (photos, stickers, audio, media, etc.):
+///
+/// 1. If the file is already stored somewhere on the Telegram servers, you don't need to reupload it:
+///    each file object has a *file_id* field, simply pass this *file_id* as a parameter instead of uploading.
+///    There are no limits for files sent this way.
+/// 2. Provide Telegram with an HTTP URL for the file to be sent.
+///    Telegram will download and send the file.
+///    5 MB max size for photos and 20 MB max for other types of content.
+/// 3. Post the file using multipart/form-data in the usual way that files are uploaded via the browser.
+///    10 MB max size for photos, 50 MB for other files.
+///
+/// Uploaded files are referenced with `attach://<name>` from the body of the call,
+/// so a call can upload several files at once.
+#[derive(Debug, PartialEq)]
+pub enum InputFile {
+    /// A file already stored on the Telegram servers
+    FileId(String),
+
+    /// An HTTP URL for Telegram to get the file from the Internet
+    Url(String),
+
+    /// A new file uploaded using multipart/form-data
+    Upload(InputFileUpload),
+}
+
+/// A new file uploaded using multipart/form-data.
+#[derive(Debug, PartialEq)]
+pub struct InputFileUpload {
+    /// The name of the form field holding the file, referenced with `attach://<name>`.
+    /// It must be unique among the files uploaded by a call and differ from the names of its parameters.
+    pub name: String,
+
+    /// The name of the file
+    pub file_name: String,
+
+    /// The contents of the file
+    pub data: Vec<u8>,
+}
+
+impl InputFile {
+    /// Uploads the file from memory, attached as the form field `name`.
+    pub fn upload(
+        name: impl Into<String>,
+        file_name: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+    ) -> Self {
+        InputFile::Upload(InputFileUpload::new(name, file_name, data))
+    }
+
+    /// Uploads the file read from `reader`, attached as the form field `name`.
+    ///
+    /// The reader is read to the end into memory before the call is built,
+    /// since requests are built with the whole body at once; the upload isn't streamed.
+    pub fn upload_buffered(
+        name: impl Into<String>,
+        file_name: impl Into<String>,
+        mut reader: impl Read,
+    ) -> io::Result<Self> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        Ok(InputFile::upload(name, file_name, data))
+    }
+
+    /// The attachment to upload alongside the call, if the file is a new upload.
+    pub fn attachment(&self) -> Option<Attachment<'_>> {
+        match self {
+            InputFile::Upload(upload) => Some(upload.attachment()),
+            _ => None,
+        }
+    }
+}
+
+impl InputFileUpload {
+    /// Creates an upload of the file from memory, attached as the form field `name`.
+    pub fn new(
+        name: impl Into<String>,
+        file_name: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+    ) -> Self {
+        InputFileUpload {
+            name: name.into(),
+            file_name: file_name.into(),
+            data: data.into(),
+        }
+    }
+
+    /// The attachment to upload alongside the call.
+    pub fn attachment(&self) -> Attachment<'_> {
+        Attachment {
+            name: &self.name,
+            file_name: &self.file_name,
+            data: &self.data,
+        }
+    }
+}
+
+impl From<InputFileUpload> for InputFile {
+    fn from(upload: InputFileUpload) -> Self {
+        InputFile::Upload(upload)
+    }
+}
+
+impl Serialize for InputFile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            InputFile::FileId(file_id) => serializer.serialize_str(file_id),
+            InputFile::Url(url) => serializer.serialize_str(url),
+            InputFile::Upload(upload) => upload.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for InputFileUpload {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(&format_args!("attach://{}", self.name))
+    }
+}
+
+impl<'de> Deserialize<'de> for InputFileUpload {
+    fn deserialize<D>(_: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Err(serde::de::Error::custom(
+            "uploaded files can't be deserialized",
+        ))
+    }
+}
+
+impl<'de> Deserialize<'de> for InputFile {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        if value.starts_with("attach://") {
+            Err(serde::de::Error::custom(
+                "uploaded files can't be deserialized",
+            ))
+        } else if value.starts_with("http://") || value.starts_with("https://") {
+            Ok(InputFile::Url(value))
+        } else {
+            Ok(InputFile::FileId(value))
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use apid::Attachment;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{
+    InputFile, InputFileUpload, InputMediaAnimation, InputMediaAudio, InputMediaDocument,
+    InputMediaPhoto, InputMediaVideo,
+};
+
+/// This object represents the content of a media message to be sent.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum InputMedia {
+    /// An animation file (GIF or H.264/MPEG-4 AVC video without sound) to be sent
+    Animation(InputMediaAnimation),
+
+    /// A general file to be sent
+    Document(InputMediaDocument),
+
+    /// An audio file to be treated as music to be sent
+    Audio(InputMediaAudio),
+
+    /// A photo to be sent
+    Photo(InputMediaPhoto),
+
+    /// A video to be sent
+    Video(InputMediaVideo),
+}
+
+impl InputMedia {
+    /// The file to send
+    pub fn media(&self) -> &InputFile {
+        match self {
+            InputMedia::Animation(animation) => &animation.media,
+            InputMedia::Document(document) => &document.media,
+            InputMedia::Audio(audio) => &audio.media,
+            InputMedia::Photo(photo) => &photo.media,
+            InputMedia::Video(video) => &video.media,
+        }
+    }
+
+    /// The thumbnail of the file to send, if any
+    pub fn thumb(&self) -> Option<&InputFileUpload> {
+        match self {
+            InputMedia::Animation(animation) => animation.thumb.as_ref(),
+            InputMedia::Document(document) => document.thumb.as_ref(),
+            InputMedia::Audio(audio) => audio.thumb.as_ref(),
+            InputMedia::Photo(_) => None,
+            InputMedia::Video(video) => video.thumb.as_ref(),
+        }
+    }
+
+    /// The files to be uploaded along with the call sending the media
+    pub fn attachments(&self) -> Vec<Attachment<'_>> {
+        self.media()
+            .attachment()
+            .into_iter()
+            .chain(self.thumb().map(InputFileUpload::attachment))
+            .collect()
+    }
+}
+
+impl From<InputMediaAnimation> for InputMedia {
+    fn from(value: InputMediaAnimation) -> Self {
+        InputMedia::Animation(value)
+    }
+}
+
+impl From<InputMediaDocument> for InputMedia {
+    fn from(value: InputMediaDocument) -> Self {
+        InputMedia::Document(value)
+    }
+}
+
+impl From<InputMediaAudio> for InputMedia {
+    fn from(value: InputMediaAudio) -> Self {
+        InputMedia::Audio(value)
+    }
+}
+
+impl From<InputMediaPhoto> for InputMedia {
+    fn from(value: InputMediaPhoto) -> Self {
+        InputMedia::Photo(value)
+    }
+}
+
+impl From<InputMediaVideo> for InputMedia {
+    fn from(value: InputMediaVideo) -> Self {
+        InputMedia::Video(value)
+    }
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Caption, InputFile, InputFileUpload, ParseMode};
+
+/// Represents an audio file to be treated as music to be sent.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct InputMediaAudio {
+    /// File to send.
+    /// Pass a file_id to send a file that exists on the Telegram servers (recommended),
+    /// pass an HTTP URL for Telegram to get a file from the Internet, or upload a new one.
+    pub media: InputFile,
+
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side.
+    /// The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320.
+    /// Thumbnails can't be reused and can be only uploaded as a new file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<InputFileUpload>,
+
+    /// Caption of the media to be sent, 0-1024 characters after entities parsing,
+    /// with special entities that appear in the caption
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Caption>,
+
+    /// Mode for parsing entities in the caption.
+    /// See formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// Duration of the audio in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i32>,
+
+    /// Performer of the audio
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performer: Option<String>,
+
+    /// Title of the audio
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
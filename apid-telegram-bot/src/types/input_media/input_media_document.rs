@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Caption, InputFile, InputFileUpload, ParseMode};
+
+/// Represents a general file to be sent.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct InputMediaDocument {
+    /// File to send.
+    /// Pass a file_id to send a file that exists on the Telegram servers (recommended),
+    /// pass an HTTP URL for Telegram to get a file from the Internet, or upload a new one.
+    pub media: InputFile,
+
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side.
+    /// The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320.
+    /// Thumbnails can't be reused and can be only uploaded as a new file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<InputFileUpload>,
+
+    /// Caption of the media to be sent, 0-1024 characters after entities parsing,
+    /// with special entities that appear in the caption
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Caption>,
+
+    /// Mode for parsing entities in the caption.
+    /// See formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// Disables automatic server-side content type detection for files uploaded using multipart/form-data.
+    /// Always *True*, if the document is sent as part of an album.
+    #[serde(default, skip_serializing_if = "crate::util::is_false")]
+    pub disable_content_type_detection: bool,
+}
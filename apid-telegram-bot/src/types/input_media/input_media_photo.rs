@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Caption, InputFile, ParseMode};
+
+/// Represents a photo to be sent.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct InputMediaPhoto {
+    /// File to send.
+    /// Pass a file_id to send a file that exists on the Telegram servers (recommended),
+    /// pass an HTTP URL for Telegram to get a file from the Internet, or upload a new one.
+    pub media: InputFile,
+
+    /// Caption of the media to be sent, 0-1024 characters after entities parsing,
+    /// with special entities that appear in the caption
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Caption>,
+
+    /// Mode for parsing entities in the caption.
+    /// See formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+}
@@ -0,0 +1,14 @@
+pub use input_media::*;
+pub use input_media_animation::*;
+pub use input_media_audio::*;
+pub use input_media_document::*;
+pub use input_media_photo::*;
+pub use input_media_video::*;
+
+#[allow(clippy::module_inception)]
+mod input_media;
+mod input_media_animation;
+mod input_media_audio;
+mod input_media_document;
+mod input_media_photo;
+mod input_media_video;
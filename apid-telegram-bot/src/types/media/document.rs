@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::PhotoSize;
+
+/// This object represents a general file (as opposed to [photos](https://core.telegram.org/bots/api#photosize), [voice messages](https://core.telegram.org/bots/api#voice) and [audio files](https://core.telegram.org/bots/api#audio)).
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Document {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: String,
+
+    /// Unique identifier for this file, which is supposed to be the same over time and for different bots.
+    /// Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+
+    /// Document thumbnail as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<PhotoSize>,
+
+    /// Original filename as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+
+    /// MIME type of the file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+
+    /// File size in bytes.
+    ///
+    /// It can be bigger than 2^31 and some programming languages may have difficulty/silent defects in interpreting it.
+    /// But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+}
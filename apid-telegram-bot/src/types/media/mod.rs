@@ -1,9 +1,21 @@
 pub use animation::*;
+pub use audio::*;
 pub use contact::*;
+pub use document::*;
 pub use location::*;
 pub use photo_size::*;
+pub use venue::*;
+pub use video::*;
+pub use video_note::*;
+pub use voice::*;
 
 mod animation;
+mod audio;
 mod contact;
+mod document;
 mod location;
 mod photo_size;
+mod venue;
+mod video;
+mod video_note;
+mod voice;
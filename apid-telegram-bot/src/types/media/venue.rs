@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::Location;
+
+/// This object represents a venue.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Venue {
+    /// Venue location. Can't be a live location
+    pub location: Location,
+
+    /// Name of the venue
+    pub title: String,
+
+    /// Address of the venue
+    pub address: String,
+
+    /// Foursquare identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_id: Option<String>,
+
+    /// Foursquare type of the venue.
+    /// (For example, “arts_entertainment/default”, “arts_entertainment/aquarium” or “food/icecream”.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<String>,
+
+    /// Google Places identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_id: Option<String>,
+
+    /// Google Places type of the venue.
+    /// (See [supported types](https://developers.google.com/places/web-service/supported_types).)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_type: Option<String>,
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::PhotoSize;
+
+/// This object represents a video file.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Video {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: String,
+
+    /// Unique identifier for this file, which is supposed to be the same over time and for different bots.
+    /// Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+
+    /// Video width as defined by sender
+    pub width: i32,
+
+    /// Video height as defined by sender
+    pub height: i32,
+
+    /// Duration of the video in seconds as defined by sender
+    pub duration: i32,
+
+    /// Video thumbnail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<PhotoSize>,
+
+    /// Original filename as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+
+    /// Mime type of a file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+
+    /// File size in bytes.
+    ///
+    /// It can be bigger than 2^31 and some programming languages may have difficulty/silent defects in interpreting it.
+    /// But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+}
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{CallbackGame, LoginUrl, True, WebAppInfo};
+
+/// This object represents one button of an inline keyboard.
+/// You **must** use exactly one of the optional fields.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct InlineKeyboardButton {
+    /// Label text on the button
+    pub text: String,
+
+    /// The action to be performed when the button is pressed.
+    #[serde(flatten)]
+    pub action: InlineKeyboardButtonAction,
+}
+
+/// The action to be performed when an inline keyboard button is pressed.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum InlineKeyboardButtonAction {
+    /// HTTP or tg:// URL to be opened when the button is pressed.
+    /// Links `tg://user?id=<user_id>` can be used to mention a user by their ID without using a username,
+    /// if this is allowed by their privacy settings.
+    Url {
+        /// HTTP or tg:// URL to be opened when the button is pressed.
+        /// Links `tg://user?id=<user_id>` can be used to mention a user by their ID without using a username,
+        /// if this is allowed by their privacy settings.
+        url: String,
+    },
+
+    /// Data to be sent in a [callback query](https://core.telegram.org/bots/api#callbackquery) to the bot when button is pressed, 1-64 bytes
+    CallbackData {
+        /// Data to be sent in a [callback query](https://core.telegram.org/bots/api#callbackquery) to the bot when button is pressed, 1-64 bytes
+        callback_data: String,
+    },
+
+    /// Description of the [Web App](https://core.telegram.org/bots/webapps) that will be launched when the user presses the button.
+    /// The Web App will be able to send an arbitrary message on behalf of the user using the method [answerWebAppQuery](https://core.telegram.org/bots/api#answerwebappquery).
+    /// Available only in private chats between a user and the bot.
+    WebApp {
+        /// Description of the [Web App](https://core.telegram.org/bots/webapps) that will be launched when the user presses the button.
+        /// The Web App will be able to send an arbitrary message on behalf of the user using the method [answerWebAppQuery](https://core.telegram.org/bots/api#answerwebappquery).
+        /// Available only in private chats between a user and the bot.
+        web_app: WebAppInfo,
+    },
+
+    /// An HTTPS URL used to automatically authorize the user.
+    /// Can be used as a replacement for the [Telegram Login Widget](https://core.telegram.org/widgets/login).
+    LoginUrl {
+        /// An HTTPS URL used to automatically authorize the user.
+        /// Can be used as a replacement for the [Telegram Login Widget](https://core.telegram.org/widgets/login).
+        login_url: LoginUrl,
+    },
+
+    /// If set, pressing the button will prompt the user to select one of their chats,
+    /// open that chat and insert the bot's username and the specified inline query in the input field.
+    /// May be empty, in which case just the bot's username will be inserted.
+    ///
+    /// **Note**:
+    /// This offers an easy way for users to start using your bot in [inline mode](https://core.telegram.org/bots/inline) when they are currently in a private chat with it.
+    /// Especially useful when combined with [*switch_pm…*](https://core.telegram.org/bots/api#answerinlinequery) actions -
+    /// in this case the user will be automatically returned to the chat they switched from, skipping the chat selection screen.
+    SwitchInlineQuery {
+        /// If set, pressing the button will prompt the user to select one of their chats,
+        /// open that chat and insert the bot's username and the specified inline query in the input field.
+        /// May be empty, in which case just the bot's username will be inserted.
+        switch_inline_query: String,
+    },
+
+    /// If set, pressing the button will insert the bot's username and the specified inline query in the current chat's input field.
+    /// May be empty, in which case only the bot's username will be inserted.
+    ///
+    /// This offers a quick way for the user to open your bot in inline mode in the same chat -
+    /// good for selecting something from multiple options.
+    SwitchInlineQueryCurrentChat {
+        /// If set, pressing the button will insert the bot's username and the specified inline query in the current chat's input field.
+        /// May be empty, in which case only the bot's username will be inserted.
+        switch_inline_query_current_chat: String,
+    },
+
+    /// Description of the game that will be launched when the user presses the button.
+    ///
+    /// **NOTE**: This type of button **must** always be the first button in the first row.
+    CallbackGame {
+        /// Description of the game that will be launched when the user presses the button.
+        callback_game: CallbackGame,
+    },
+
+    /// Specify *True*, to send a [Pay button](https://core.telegram.org/bots/api#payments).
+    ///
+    /// **NOTE**: This type of button **must** always be the first button in the first row and can only be used in invoice messages.
+    Pay {
+        /// Specify *True*, to send a [Pay button](https://core.telegram.org/bots/api#payments).
+        pay: True,
+    },
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::InlineKeyboardButton;
+
+/// This object represents an [inline keyboard](https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating) that appears right next to the message it belongs to.
+///
+/// **Note**:
+/// This will only work in Telegram versions released after 9 April, 2016.
+/// Older clients will display *unsupported message*.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct InlineKeyboardMarkup {
+    /// Array of button rows, each represented by an Array of [`InlineKeyboardButton`] objects
+    pub inline_keyboard: Vec<Vec<InlineKeyboardButton>>,
+}
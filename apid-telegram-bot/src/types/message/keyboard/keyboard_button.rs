@@ -12,8 +12,9 @@ pub struct Keyboardbutton {
     pub text: String,
 
     /// The action to be performed when a keyboard button is pressed.
-    #[serde(flatten)]
-    pub action: KeyboardbuttonAction,
+    /// If none, the text of the button will be sent as a message.
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub action: Option<KeyboardbuttonAction>,
 }
 
 /// The action to be performed when a keyboard button is pressed.
@@ -2,7 +2,6 @@ use serde::{Deserialize, Serialize};
 
 /// This object represents type of a poll, which is allowed to be created and sent when the corresponding button is pressed.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case", tag = "type")]
 pub struct KeyboardButtonPollType {
     /// The actual kind
     #[serde(flatten, skip_serializing_if = "Option::is_none")]
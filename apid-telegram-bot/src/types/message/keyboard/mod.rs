@@ -1,5 +1,17 @@
+pub use force_reply::*;
+pub use inline_keyboard_button::*;
+pub use inline_keyboard_markup::*;
 pub use keyboard_button::*;
 pub use keyboard_button_poll_type::*;
+pub use reply_keyboard_markup::*;
+pub use reply_keyboard_remove::*;
+pub use reply_markup::*;
 
+mod force_reply;
+mod inline_keyboard_button;
+mod inline_keyboard_markup;
 mod keyboard_button;
 mod keyboard_button_poll_type;
+mod reply_keyboard_markup;
+mod reply_keyboard_remove;
+mod reply_markup;
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ForceReply, InlineKeyboardMarkup, ReplyKeyboardMarkup, ReplyKeyboardRemove};
+
+/// Additional interface options sent along with a message.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ReplyMarkup {
+    /// An [inline keyboard](https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating)
+    InlineKeyboard(InlineKeyboardMarkup),
+
+    /// A [custom reply keyboard](https://core.telegram.org/bots#keyboards)
+    ReplyKeyboard(ReplyKeyboardMarkup),
+
+    /// Instructions to remove reply keyboard
+    ReplyKeyboardRemove(ReplyKeyboardRemove),
+
+    /// Instructions to force a reply from the user
+    ForceReply(ForceReply),
+}
+
+impl From<InlineKeyboardMarkup> for ReplyMarkup {
+    fn from(value: InlineKeyboardMarkup) -> Self {
+        ReplyMarkup::InlineKeyboard(value)
+    }
+}
+
+impl From<ReplyKeyboardMarkup> for ReplyMarkup {
+    fn from(value: ReplyKeyboardMarkup) -> Self {
+        ReplyMarkup::ReplyKeyboard(value)
+    }
+}
+
+impl From<ReplyKeyboardRemove> for ReplyMarkup {
+    fn from(value: ReplyKeyboardRemove) -> Self {
+        ReplyMarkup::ReplyKeyboardRemove(value)
+    }
+}
+
+impl From<ForceReply> for ReplyMarkup {
+    fn from(value: ForceReply) -> Self {
+        ReplyMarkup::ForceReply(value)
+    }
+}
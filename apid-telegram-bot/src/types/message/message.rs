@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 
 use crate::types::{
-    Animation, Chat, Contact, Dice, Game, Location, MessageAutoDeleteTimerChanged, MessageEntity,
-    PhotoSize, Poll, True, User, VideoChatEnded, VideoChatParticipantsInvited, VideoChatScheduled,
-    VideoChatStarted, WebAppData,
+    Animation, Audio, Chat, Contact, Dice, Document, Game, InlineKeyboardMarkup, Invoice, Location,
+    MessageAutoDeleteTimerChanged, MessageEntity, PassportData, PhotoSize, Poll,
+    ProximityAlertTriggered, Sticker, SuccessfulPayment, True, User, Venue, Video, VideoChatEnded,
+    VideoChatParticipantsInvited, VideoChatScheduled, VideoChatStarted, VideoNote, Voice,
+    WebAppData,
 };
 
 /// This object represents a message.
@@ -160,7 +162,10 @@ pub enum MessageContent {
     },
 
     /// Message is a sticker
-    Sticker { sticker: Sticker },
+    Sticker {
+        /// Message is a sticker, information about the sticker
+        sticker: Sticker,
+    },
 
     /// Message is a video
     Video {
@@ -173,7 +178,10 @@ pub enum MessageContent {
     },
 
     /// Message is a [video note](https://telegram.org/blog/video-messages-and-telescope)
-    VideoNote { video_note: VideoNote },
+    VideoNote {
+        /// Message is a [video note](https://telegram.org/blog/video-messages-and-telescope), information about the video message
+        video_note: VideoNote,
+    },
 
     /// Message is a voice message
     Voice {
@@ -317,11 +325,16 @@ pub enum MessageContent {
         successful_payment: SuccessfulPayment,
     },
 
+    /// The domain name of the website on which the user has logged in.
+    /// [More about Telegram Login »](https://core.telegram.org/widgets/login)
     Login {
         /// The domain name of the website on which the user has logged in.
         /// [More about Telegram Login »](https://core.telegram.org/widgets/login)
         connected_website: String,
+    },
 
+    /// Telegram Passport data
+    PassportData {
         /// Telegram Passport data
         passport_data: PassportData,
     },
@@ -359,78 +372,6 @@ pub enum MessageContent {
     },
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct Audio {
-    // TODO:
-    __never_happen: String,
-}
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct Document {
-    // TODO:
-    __never_happen: String,
-}
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct Sticker {
-    // TODO:
-    __never_happen: String,
-}
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct Video {
-    // TODO:
-    __never_happen: String,
-}
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct VideoNote {
-    // TODO:
-    __never_happen: String,
-}
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct Voice {
-    // TODO:
-    __never_happen: String,
-}
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct Venue {
-    // TODO:
-    __never_happen: String,
-}
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct Invoice {
-    // TODO:
-    __never_happen: String,
-}
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct SuccessfulPayment {
-    // TODO:
-    __never_happen: String,
-}
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct PassportData {
-    // TODO:
-    __never_happen: String,
-}
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct ProximityAlertTriggered {
-    // TODO:
-    __never_happen: String,
-}
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct InlineKeyboardMarkup {
-    // TODO:
-    __never_happen: String,
-}
-
 /// Caption for the animation, audio, document, photo, video or voice
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Caption {
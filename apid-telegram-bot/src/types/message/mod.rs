@@ -3,6 +3,8 @@ pub use keyboard::*;
 pub use message::*;
 pub use message_entity::*;
 pub use message_id::*;
+pub use parse_mode::*;
+pub use proximity_alert_triggered::*;
 pub use service::*;
 pub use video_chat::*;
 
@@ -11,5 +13,7 @@ mod keyboard;
 mod message;
 mod message_entity;
 mod message_id;
+mod parse_mode;
+mod proximity_alert_triggered;
 mod service;
 mod video_chat;
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// Mode for parsing entities in the text.
+/// See [formatting options](https://core.telegram.org/bots/api#formatting-options) for more details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum ParseMode {
+    /// [MarkdownV2 style](https://core.telegram.org/bots/api#markdownv2-style)
+    MarkdownV2,
+
+    /// [HTML style](https://core.telegram.org/bots/api#html-style)
+    HTML,
+
+    /// [Markdown style](https://core.telegram.org/bots/api#markdown-style),
+    /// a legacy mode retained for backward compatibility
+    Markdown,
+}
@@ -1,4 +1,5 @@
 //! This module contains types representing Telegram API objects.
+pub use api_response::*;
 pub use bool_const::*;
 ///
 pub use bot::*;
@@ -6,33 +7,46 @@ pub use callback_query::*;
 pub use chat::*;
 pub use chat_join_request::*;
 pub use chat_member_updated::*;
+pub use file::*;
 pub use game::*;
 pub use inline_mode::*;
+pub use input_file::*;
+pub use input_media::*;
 pub use media::*;
 pub use message::*;
 pub use passport::*;
+pub use payment::*;
 pub use poll::*;
 pub use pre_checkout_query::*;
+pub use response_parameters::*;
 pub use shipping_query::*;
+pub use sticker::*;
 pub use update::*;
 pub use user::*;
 pub use web_app::*;
 pub use webhook_info::*;
 
+mod api_response;
 mod bool_const;
 mod bot;
 mod callback_query;
 mod chat;
 mod chat_join_request;
 mod chat_member_updated;
+mod file;
 mod game;
 mod inline_mode;
+mod input_file;
+mod input_media;
 mod media;
 mod message;
 mod passport;
+mod payment;
 mod poll;
 mod pre_checkout_query;
+mod response_parameters;
 mod shipping_query;
+mod sticker;
 mod update;
 mod user;
 mod web_app;
@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+/// The decrypted [`EncryptedCredentials`](crate::types::EncryptedCredentials),
+/// containing the secrets to decrypt the Telegram Passport elements shared with the bot.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Credentials {
+    /// Credentials for encrypted data
+    pub secure_data: SecureData,
+
+    /// Bot-specified nonce
+    pub nonce: String,
+}
+
+/// The credentials required to decrypt encrypted data,
+/// present for every element the user shared.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SecureData {
+    /// Credentials for encrypted personal details
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_details: Option<SecureValue>,
+
+    /// Credentials for encrypted passport
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passport: Option<SecureValue>,
+
+    /// Credentials for encrypted internal passport
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal_passport: Option<SecureValue>,
+
+    /// Credentials for encrypted driver license
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver_license: Option<SecureValue>,
+
+    /// Credentials for encrypted ID card
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_card: Option<SecureValue>,
+
+    /// Credentials for encrypted residential address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<SecureValue>,
+
+    /// Credentials for encrypted utility bill
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utility_bill: Option<SecureValue>,
+
+    /// Credentials for encrypted bank statement
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_statement: Option<SecureValue>,
+
+    /// Credentials for encrypted rental agreement
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rental_agreement: Option<SecureValue>,
+
+    /// Credentials for encrypted registration from internal passport
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passport_registration: Option<SecureValue>,
+
+    /// Credentials for encrypted temporary registration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temporary_registration: Option<SecureValue>,
+}
+
+/// The credentials required to decrypt encrypted values.
+/// All fields are optional and depend on fields that were requested.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SecureValue {
+    /// Credentials for encrypted Telegram Passport data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<DataCredentials>,
+
+    /// Credentials for an encrypted document's front side
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub front_side: Option<FileCredentials>,
+
+    /// Credentials for an encrypted document's reverse side
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reverse_side: Option<FileCredentials>,
+
+    /// Credentials for an encrypted selfie of the user with a document
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selfie: Option<FileCredentials>,
+
+    /// Credentials for an encrypted translation of the document
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub translation: Vec<FileCredentials>,
+
+    /// Credentials for encrypted files
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<FileCredentials>,
+}
+
+/// These credentials can be used to decrypt encrypted data from the *data* field in [`EncryptedPassportElement`](crate::types::EncryptedPassportElement).
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DataCredentials {
+    /// Checksum of encrypted data
+    pub data_hash: String,
+
+    /// Secret of encrypted data
+    pub secret: String,
+}
+
+/// These credentials can be used to decrypt encrypted files from the *front_side*, *reverse_side*, *selfie*, *files* and *translation* fields in [`EncryptedPassportElement`](crate::types::EncryptedPassportElement).
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct FileCredentials {
+    /// Checksum of encrypted file
+    pub file_hash: String,
+
+    /// Secret of encrypted file
+    pub secret: String,
+}
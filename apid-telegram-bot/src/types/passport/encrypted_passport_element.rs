@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::PassportFile;
+
+/// Describes documents or other Telegram Passport elements shared with the bot by the user.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct EncryptedPassportElement {
+    /// Element type
+    #[serde(rename = "type")]
+    pub kind: EncryptedPassportElementKind,
+
+    /// Base64-encoded encrypted Telegram Passport element data provided by the user,
+    /// available for “personal_details”, “passport”, “driver_license”, “identity_card”, “internal_passport” and “address” types.
+    /// Can be decrypted and verified using the accompanying [`EncryptedCredentials`](crate::types::EncryptedCredentials).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+
+    /// User's verified phone number, available only for “phone_number” type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+
+    /// User's verified email address, available only for “email” type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// Array of encrypted files with documents provided by the user,
+    /// available for “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration” and “temporary_registration” types.
+    /// Files can be decrypted and verified using the accompanying [`EncryptedCredentials`](crate::types::EncryptedCredentials).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<PassportFile>,
+
+    /// Encrypted file with the front side of the document, provided by the user.
+    /// Available for “passport”, “driver_license”, “identity_card” and “internal_passport”.
+    /// The file can be decrypted and verified using the accompanying [`EncryptedCredentials`](crate::types::EncryptedCredentials).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub front_side: Option<PassportFile>,
+
+    /// Encrypted file with the reverse side of the document, provided by the user.
+    /// Available for “driver_license” and “identity_card”.
+    /// The file can be decrypted and verified using the accompanying [`EncryptedCredentials`](crate::types::EncryptedCredentials).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reverse_side: Option<PassportFile>,
+
+    /// Encrypted file with the selfie of the user holding a document, provided by the user;
+    /// available for “passport”, “driver_license”, “identity_card” and “internal_passport”.
+    /// The file can be decrypted and verified using the accompanying [`EncryptedCredentials`](crate::types::EncryptedCredentials).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selfie: Option<PassportFile>,
+
+    /// Array of encrypted files with translated versions of documents provided by the user.
+    /// Available if requested for “passport”, “driver_license”, “identity_card”, “internal_passport”, “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration” and “temporary_registration” types.
+    /// Files can be decrypted and verified using the accompanying [`EncryptedCredentials`](crate::types::EncryptedCredentials).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub translation: Vec<PassportFile>,
+
+    /// Base64-encoded element hash for using in [`PassportElementError::Unspecified`](crate::types::PassportElementError::Unspecified)
+    pub hash: String,
+}
+
+/// The kind of Telegram Passport element
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EncryptedPassportElementKind {
+    /// Personal details
+    PersonalDetails,
+    /// Passport
+    Passport,
+    /// Driver license
+    DriverLicense,
+    /// Identity card
+    IdentityCard,
+    /// Internal passport
+    InternalPassport,
+    /// Address
+    Address,
+    /// Utility bill
+    UtilityBill,
+    /// Bank statement
+    BankStatement,
+    /// Rental agreement
+    RentalAgreement,
+    /// Passport registration
+    PassportRegistration,
+    /// Temporary registration
+    TemporaryRegistration,
+    /// Phone number
+    PhoneNumber,
+    /// Email
+    Email,
+}
@@ -21,6 +21,7 @@ pub struct LoginUrl {
     pub url: String,
 
     /// New text of the button in forwarded messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_text: Option<String>,
 
     /// Username of a bot, which will be used for user authorization.
@@ -28,6 +29,7 @@ pub struct LoginUrl {
     /// If not specified, the current bot's username will be assumed.
     /// The url's domain must be the same as the domain linked with the bot.
     /// See [Linking your domain to the bot](https://core.telegram.org/widgets/login#linking-your-domain-to-the-bot) for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bot_username: Option<String>,
 
     /// Pass *True* to request the permission for your bot to send messages to the user.
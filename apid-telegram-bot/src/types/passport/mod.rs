@@ -1,3 +1,17 @@
+pub use credentials::*;
+pub use encrypted_credentials::*;
+pub use encrypted_passport_element::*;
 pub use login_url::*;
+pub use passport_data::*;
+pub use passport_element_data::*;
+pub use passport_element_error::*;
+pub use passport_file::*;
 
+mod credentials;
+mod encrypted_credentials;
+mod encrypted_passport_element;
 mod login_url;
+mod passport_data;
+mod passport_element_data;
+mod passport_element_error;
+mod passport_file;
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{EncryptedCredentials, EncryptedPassportElement};
+
+/// Describes Telegram Passport data shared with the bot by the user.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct PassportData {
+    /// Array with information about documents and other Telegram Passport elements that was shared with the bot
+    pub data: Vec<EncryptedPassportElement>,
+
+    /// Encrypted credentials required to decrypt the data
+    pub credentials: EncryptedCredentials,
+}
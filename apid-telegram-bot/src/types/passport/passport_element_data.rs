@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+/// The decrypted data of the “personal_details” Telegram Passport element.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct PersonalDetails {
+    /// First Name
+    pub first_name: String,
+
+    /// Last Name
+    pub last_name: String,
+
+    /// Middle Name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub middle_name: Option<String>,
+
+    /// Date of birth in DD.MM.YYYY format
+    pub birth_date: String,
+
+    /// Gender, male or female
+    pub gender: String,
+
+    /// Citizenship (ISO 3166-1 alpha-2 country code)
+    pub country_code: String,
+
+    /// Country of residence (ISO 3166-1 alpha-2 country code)
+    pub residence_country_code: String,
+
+    /// First Name in the language of the user's country of residence
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name_native: Option<String>,
+
+    /// Last Name in the language of the user's country of residence
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name_native: Option<String>,
+
+    /// Middle Name in the language of the user's country of residence
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub middle_name_native: Option<String>,
+}
+
+/// The decrypted data of the “address” Telegram Passport element.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ResidentialAddress {
+    /// First line for the address
+    pub street_line1: String,
+
+    /// Second line for the address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street_line2: Option<String>,
+
+    /// City
+    pub city: String,
+
+    /// State
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+
+    /// ISO 3166-1 alpha-2 country code
+    pub country_code: String,
+
+    /// Address post code
+    pub post_code: String,
+}
+
+/// The decrypted data of the “passport”, “driver_license”, “identity_card” and “internal_passport” Telegram Passport elements.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct IdDocumentData {
+    /// Document number
+    pub document_no: String,
+
+    /// Date of expiry, in DD.MM.YYYY format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry_date: Option<String>,
+}
@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::EncryptedPassportElementKind;
+
+/// This object represents an error in the Telegram Passport element which was submitted that should be resolved by the user.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "source")]
+pub enum PassportElementError {
+    /// Represents an issue in one of the data fields that was provided by the user.
+    /// The error is considered resolved when the field's value changes.
+    Data {
+        /// The section of the user's Telegram Passport which has the error,
+        /// one of “personal_details”, “passport”, “driver_license”, “identity_card”, “internal_passport”, “address”
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementKind,
+
+        /// Name of the data field which has the error
+        field_name: String,
+
+        /// Base64-encoded data hash
+        data_hash: String,
+
+        /// Error message
+        message: String,
+    },
+
+    /// Represents an issue with the front side of a document.
+    /// The error is considered resolved when the file with the front side of the document changes.
+    FrontSide {
+        /// The section of the user's Telegram Passport which has the issue,
+        /// one of “passport”, “driver_license”, “identity_card”, “internal_passport”
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementKind,
+
+        /// Base64-encoded hash of the file with the front side of the document
+        file_hash: String,
+
+        /// Error message
+        message: String,
+    },
+
+    /// Represents an issue with the reverse side of a document.
+    /// The error is considered resolved when the file with reverse side of the document changes.
+    ReverseSide {
+        /// The section of the user's Telegram Passport which has the issue,
+        /// one of “driver_license”, “identity_card”
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementKind,
+
+        /// Base64-encoded hash of the file with the reverse side of the document
+        file_hash: String,
+
+        /// Error message
+        message: String,
+    },
+
+    /// Represents an issue with the selfie with a document.
+    /// The error is considered resolved when the file with the selfie changes.
+    Selfie {
+        /// The section of the user's Telegram Passport which has the issue,
+        /// one of “passport”, “driver_license”, “identity_card”, “internal_passport”
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementKind,
+
+        /// Base64-encoded hash of the file with the selfie
+        file_hash: String,
+
+        /// Error message
+        message: String,
+    },
+
+    /// Represents an issue with a document scan.
+    /// The error is considered resolved when the file with the document scan changes.
+    File {
+        /// The section of the user's Telegram Passport which has the issue,
+        /// one of “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration”, “temporary_registration”
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementKind,
+
+        /// Base64-encoded file hash
+        file_hash: String,
+
+        /// Error message
+        message: String,
+    },
+
+    /// Represents an issue with a list of scans.
+    /// The error is considered resolved when the list of files containing the scans changes.
+    Files {
+        /// The section of the user's Telegram Passport which has the issue,
+        /// one of “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration”, “temporary_registration”
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementKind,
+
+        /// List of base64-encoded file hashes
+        file_hashes: Vec<String>,
+
+        /// Error message
+        message: String,
+    },
+
+    /// Represents an issue with one of the files that constitute the translation of a document.
+    /// The error is considered resolved when the file changes.
+    TranslationFile {
+        /// Type of element of the user's Telegram Passport which has the issue,
+        /// one of “passport”, “driver_license”, “identity_card”, “internal_passport”, “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration”, “temporary_registration”
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementKind,
+
+        /// Base64-encoded file hash
+        file_hash: String,
+
+        /// Error message
+        message: String,
+    },
+
+    /// Represents an issue with the translated version of a document.
+    /// The error is considered resolved when a file with the document translation change.
+    TranslationFiles {
+        /// Type of element of the user's Telegram Passport which has the issue,
+        /// one of “passport”, “driver_license”, “identity_card”, “internal_passport”, “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration”, “temporary_registration”
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementKind,
+
+        /// List of base64-encoded file hashes
+        file_hashes: Vec<String>,
+
+        /// Error message
+        message: String,
+    },
+
+    /// Represents an issue in an unspecified place.
+    /// The error is considered resolved when new data is added.
+    Unspecified {
+        /// Type of element of the user's Telegram Passport which has the issue
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementKind,
+
+        /// Base64-encoded element hash
+        element_hash: String,
+
+        /// Error message
+        message: String,
+    },
+}
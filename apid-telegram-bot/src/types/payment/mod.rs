@@ -0,0 +1,13 @@
+pub use invoice::*;
+pub use labeled_price::*;
+pub use order_info::*;
+pub use shipping_address::*;
+pub use shipping_option::*;
+pub use successful_payment::*;
+
+mod invoice;
+mod labeled_price;
+mod order_info;
+mod shipping_address;
+mod shipping_option;
+mod successful_payment;
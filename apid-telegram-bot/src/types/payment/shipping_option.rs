@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::LabeledPrice;
+
+/// This object represents one shipping option.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ShippingOption {
+    /// Shipping option identifier
+    pub id: String,
+
+    /// Option title
+    pub title: String,
+
+    /// List of price portions
+    pub prices: Vec<LabeledPrice>,
+}
@@ -48,13 +48,17 @@ pub enum PollKind {
         /// 0-based identifier of the correct answer option.
         /// Available only for polls in the quiz mode, which are closed,
         /// or was sent (not forwarded) by the bot or to the private chat with the bot.
+        /// Required by [`SendPoll`](crate::calls::SendPoll).
+        #[serde(skip_serializing_if = "Option::is_none")]
         correct_option_id: Option<i32>,
 
         /// Text that is shown when a user chooses an incorrect answer or taps on the lamp icon in a quiz-style poll, 0-200 characters
+        #[serde(skip_serializing_if = "Option::is_none")]
         explanation: Option<String>,
 
         /// Special entities like usernames, URLs, bot commands, etc.
         /// that appear in the explanation
+        #[serde(skip_serializing_if = "Option::is_none")]
         explanation_entities: Option<Vec<MessageEntity>>,
     },
 }
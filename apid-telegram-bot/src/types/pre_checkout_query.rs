@@ -1,7 +1,33 @@
 use serde::{Deserialize, Serialize};
 
+use crate::types::{OrderInfo, User};
+
+/// This object contains information about an incoming pre-checkout query.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct PreCheckoutQuery {
-    // TODO:
-    __never_happen: String,
+    /// Unique query identifier
+    pub id: String,
+
+    /// User who sent the query
+    pub from: User,
+
+    /// Three-letter ISO 4217 [currency](https://core.telegram.org/bots/payments#supported-currencies) code
+    pub currency: String,
+
+    /// Total price in the *smallest units* of the currency (integer, **not** float/double).
+    /// For example, for a price of `US$ 1.45` pass `amount = 145`.
+    /// See the *exp* parameter in [currencies.json](https://core.telegram.org/bots/payments/currencies.json),
+    /// it shows the number of digits past the decimal point for each currency (2 for the majority of currencies).
+    pub total_amount: i32,
+
+    /// Bot specified invoice payload
+    pub invoice_payload: String,
+
+    /// Identifier of the shipping option chosen by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_option_id: Option<String>,
+
+    /// Order information provided by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_info: Option<OrderInfo>,
 }
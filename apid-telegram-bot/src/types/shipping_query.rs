@@ -1,7 +1,19 @@
 use serde::{Deserialize, Serialize};
 
+use crate::types::{ShippingAddress, User};
+
+/// This object contains information about an incoming shipping query.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct ShippingQuery {
-    // TODO:
-    __never_happen: String,
+    /// Unique query identifier
+    pub id: String,
+
+    /// User who sent the query
+    pub from: User,
+
+    /// Bot specified invoice payload
+    pub invoice_payload: String,
+
+    /// User specified shipping address
+    pub shipping_address: ShippingAddress,
 }
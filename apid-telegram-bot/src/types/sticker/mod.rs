@@ -0,0 +1,8 @@
+pub use mask_position::*;
+pub use sticker::*;
+pub use sticker_set::*;
+
+mod mask_position;
+#[allow(clippy::module_inception)]
+mod sticker;
+mod sticker_set;
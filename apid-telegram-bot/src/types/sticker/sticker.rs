@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{File, MaskPosition, PhotoSize};
+
+/// This object represents a sticker.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Sticker {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: String,
+
+    /// Unique identifier for this file, which is supposed to be the same over time and for different bots.
+    /// Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+
+    /// Type of the sticker, currently one of “regular”, “mask”, “custom_emoji”.
+    /// The type of the sticker is independent from its format, which is determined by the fields *is_animated* and *is_video*.
+    #[serde(rename = "type")]
+    pub kind: StickerKind,
+
+    /// Sticker width
+    pub width: i32,
+
+    /// Sticker height
+    pub height: i32,
+
+    /// *True*, if the sticker is [animated](https://telegram.org/blog/animated-stickers)
+    pub is_animated: bool,
+
+    /// *True*, if the sticker is a [video sticker](https://telegram.org/blog/video-stickers-better-reactions)
+    pub is_video: bool,
+
+    /// Sticker thumbnail in the .WEBP or .JPG format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<PhotoSize>,
+
+    /// Emoji associated with the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+
+    /// Name of the sticker set to which the sticker belongs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_name: Option<String>,
+
+    /// For premium regular stickers, premium animation for the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub premium_animation: Option<File>,
+
+    /// For mask stickers, the position where the mask should be placed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<MaskPosition>,
+
+    /// For custom emoji stickers, unique identifier of the custom emoji
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_emoji_id: Option<String>,
+
+    /// File size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i32>,
+}
+
+impl Sticker {
+    /// The format of the sticker, determined by the fields *is_animated* and *is_video*.
+    pub fn format(&self) -> StickerFormat {
+        StickerFormat::new(self.is_animated, self.is_video)
+    }
+}
+
+/// The kind of sticker
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StickerKind {
+    /// Regular sticker
+    Regular,
+    /// Mask sticker
+    Mask,
+    /// Custom emoji sticker
+    CustomEmoji,
+}
+
+/// The format of sticker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StickerFormat {
+    /// Static sticker in the .WEBP format
+    Static,
+    /// [Animated sticker](https://telegram.org/blog/animated-stickers) in the .TGS format
+    Animated,
+    /// [Video sticker](https://telegram.org/blog/video-stickers-better-reactions) in the .WEBM format
+    Video,
+}
+
+impl StickerFormat {
+    /// Determines the format from the fields *is_animated* and *is_video* of a sticker or sticker set.
+    pub fn new(is_animated: bool, is_video: bool) -> Self {
+        if is_animated {
+            StickerFormat::Animated
+        } else if is_video {
+            StickerFormat::Video
+        } else {
+            StickerFormat::Static
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{PhotoSize, Sticker, StickerFormat, StickerKind};
+
+/// This object represents a sticker set.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct StickerSet {
+    /// Sticker set name
+    pub name: String,
+
+    /// Sticker set title
+    pub title: String,
+
+    /// Type of stickers in the set, currently one of “regular”, “mask”, “custom_emoji”
+    #[serde(rename = "sticker_type")]
+    pub sticker_kind: StickerKind,
+
+    /// *True*, if the sticker set contains [animated stickers](https://telegram.org/blog/animated-stickers)
+    pub is_animated: bool,
+
+    /// *True*, if the sticker set contains [video stickers](https://telegram.org/blog/video-stickers-better-reactions)
+    pub is_video: bool,
+
+    /// List of all set stickers
+    pub stickers: Vec<Sticker>,
+
+    /// Sticker set thumbnail in the .WEBP, .TGS, or .WEBM format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<PhotoSize>,
+}
+
+impl StickerSet {
+    /// The format of stickers in the set, determined by the fields *is_animated* and *is_video*.
+    pub fn format(&self) -> StickerFormat {
+        StickerFormat::new(self.is_animated, self.is_video)
+    }
+}
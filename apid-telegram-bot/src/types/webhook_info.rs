@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::types::UpdateKind;
+
 /// Describes the current status of a webhook.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct WebhookInfo {
@@ -35,5 +37,5 @@ pub struct WebhookInfo {
     /// A list of update types the bot is subscribed to.
     /// Defaults to all update types except *chat_member*
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_updates: Option<Vec<String>>,
+    pub allowed_updates: Option<Vec<UpdateKind>>,
 }
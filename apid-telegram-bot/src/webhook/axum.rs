@@ -0,0 +1,46 @@
+use axum::{
+    body::{to_bytes, Body},
+    extract::{FromRef, FromRequest},
+    http::{Request, StatusCode},
+    response::{IntoResponse, Response},
+};
+
+use crate::{types::Update, WebhookError, WebhookHandler, BODY_LIMIT};
+
+/// An [axum](https://docs.rs/axum) extractor receiving the [`Update`] sent to a webhook
+/// with the [`WebhookHandler`] of the router state.
+///
+/// The request is rejected with *401 Unauthorized* if the secret token doesn't match, before its body is read,
+/// or with *400 Bad Request* if the body is larger than [`BODY_LIMIT`] or isn't an update.
+#[derive(Debug, PartialEq)]
+pub struct WebhookUpdate(pub Update);
+
+impl<S> FromRequest<S> for WebhookUpdate
+where
+    S: Send + Sync,
+    WebhookHandler: FromRef<S>,
+{
+    type Rejection = WebhookError;
+
+    async fn from_request(request: Request<Body>, state: &S) -> Result<Self, Self::Rejection> {
+        let handler = WebhookHandler::from_ref(state);
+        let (parts, body) = request.into_parts();
+        handler.verify_headers(
+            parts
+                .headers
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_bytes())),
+        )?;
+        let body = to_bytes(body, BODY_LIMIT)
+            .await
+            .map_err(|error| WebhookError::Body(error.into()))?;
+        super::webhook_handler::parse_update(&body).map(WebhookUpdate)
+    }
+}
+
+impl IntoResponse for WebhookError {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status_code()).unwrap_or(StatusCode::BAD_REQUEST);
+        (status, self.to_string()).into_response()
+    }
+}
@@ -0,0 +1,30 @@
+use http::Request;
+use http_body::Body;
+use http_body_util::{BodyExt, Limited};
+
+use crate::{types::Update, WebhookError, WebhookHandler, BODY_LIMIT};
+
+impl WebhookHandler {
+    /// Receives the update sent in an [`http::Request`], such as the `Request<Incoming>` served by [hyper](https://docs.rs/hyper).
+    ///
+    /// The secret token is checked before the body is read, and bodies larger than [`BODY_LIMIT`] are rejected.
+    pub async fn handle_request<B>(&self, request: Request<B>) -> Result<Update, WebhookError>
+    where
+        B: Body,
+        B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let (parts, body) = request.into_parts();
+        self.verify_headers(
+            parts
+                .headers
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_bytes())),
+        )?;
+        let body = Limited::new(body, BODY_LIMIT)
+            .collect()
+            .await
+            .map_err(WebhookError::Body)?
+            .to_bytes();
+        super::webhook_handler::parse_update(&body)
+    }
+}
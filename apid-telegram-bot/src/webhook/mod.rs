@@ -0,0 +1,11 @@
+#[cfg(feature = "axum")]
+pub use self::axum::*;
+pub use webhook_error::*;
+pub use webhook_handler::*;
+
+#[cfg(feature = "axum")]
+mod axum;
+#[cfg(feature = "hyper")]
+mod hyper;
+mod webhook_error;
+mod webhook_handler;
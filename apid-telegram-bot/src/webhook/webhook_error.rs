@@ -0,0 +1,48 @@
+use std::fmt;
+
+/// An error occurred while receiving an update sent to a webhook.
+#[derive(Debug)]
+pub enum WebhookError {
+    /// The request has no secret token header although the webhook was set with one
+    MissingSecretToken,
+
+    /// The secret token of the request doesn't match the one the webhook was set with
+    InvalidSecretToken,
+
+    /// The body of the request couldn't be read
+    Body(Box<dyn std::error::Error + Send + Sync>),
+
+    /// The body of the request isn't an update
+    Json(serde_json::Error),
+}
+
+impl WebhookError {
+    /// The HTTP status code to respond to the request with.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            WebhookError::MissingSecretToken | WebhookError::InvalidSecretToken => 401,
+            WebhookError::Body(_) | WebhookError::Json(_) => 400,
+        }
+    }
+}
+
+impl fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebhookError::MissingSecretToken => f.write_str("missing secret token"),
+            WebhookError::InvalidSecretToken => f.write_str("invalid secret token"),
+            WebhookError::Body(error) => write!(f, "body error: {}", error),
+            WebhookError::Json(error) => write!(f, "JSON error: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for WebhookError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WebhookError::Body(error) => Some(error.as_ref()),
+            WebhookError::Json(error) => Some(error),
+            _ => None,
+        }
+    }
+}
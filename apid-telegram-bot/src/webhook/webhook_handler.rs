@@ -0,0 +1,94 @@
+use std::fmt;
+
+use crate::{types::Update, WebhookError};
+
+/// The header Telegram sends the *secret_token* of [`SetWebhook`](crate::calls::SetWebhook) in.
+pub const SECRET_TOKEN_HEADER: &str = "X-Telegram-Bot-Api-Secret-Token";
+
+/// The maximum size of a request body read by the framework adapters, e.g. [`WebhookHandler::handle_request`].
+pub const BODY_LIMIT: usize = 16 * 1024 * 1024;
+
+/// Receives the [`Update`]s Telegram sends to a webhook, independent of any web framework.
+///
+/// Pass the headers and the body of every request sent to the webhook to [`WebhookHandler::handle`],
+/// which verifies the secret token and parses the update.
+#[derive(Clone)]
+pub struct WebhookHandler {
+    secret_token: Option<String>,
+}
+
+impl WebhookHandler {
+    /// Creates a handler accepting requests with the *secret_token* passed to [`SetWebhook`](crate::calls::SetWebhook).
+    pub fn new(secret_token: impl Into<String>) -> Self {
+        WebhookHandler {
+            secret_token: Some(secret_token.into()),
+        }
+    }
+
+    /// Creates a handler accepting every request, for webhooks set without a *secret_token*.
+    pub fn without_secret_token() -> Self {
+        WebhookHandler { secret_token: None }
+    }
+
+    /// Checks the [`SECRET_TOKEN_HEADER`] of the request, then parses its body as an [`Update`].
+    /// Header names are matched case-insensitively.
+    pub fn handle<'a, H>(&self, headers: H, body: &[u8]) -> Result<Update, WebhookError>
+    where
+        H: IntoIterator<Item = (&'a str, &'a [u8])>,
+    {
+        self.verify_headers(headers)?;
+        parse_update(body)
+    }
+
+    /// Checks the [`SECRET_TOKEN_HEADER`] of the request, before its body is read.
+    /// Header names are matched case-insensitively.
+    pub fn verify_headers<'a, H>(&self, headers: H) -> Result<(), WebhookError>
+    where
+        H: IntoIterator<Item = (&'a str, &'a [u8])>,
+    {
+        let secret_token = headers
+            .into_iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(SECRET_TOKEN_HEADER))
+            .map(|(_, value)| value);
+        self.verify(secret_token)
+    }
+
+    /// Checks the value of the [`SECRET_TOKEN_HEADER`] of the request, if any.
+    pub fn verify(&self, secret_token: Option<&[u8]>) -> Result<(), WebhookError> {
+        match (&self.secret_token, secret_token) {
+            (None, _) => Ok(()),
+            (Some(_), None) => Err(WebhookError::MissingSecretToken),
+            (Some(expected), Some(actual)) if constant_time_eq(expected.as_bytes(), actual) => {
+                Ok(())
+            }
+            (Some(_), Some(_)) => Err(WebhookError::InvalidSecretToken),
+        }
+    }
+}
+
+impl fmt::Debug for WebhookHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookHandler")
+            .field(
+                "secret_token",
+                &self.secret_token.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
+}
+
+/// Parses the body of a request whose headers have already been verified.
+pub(crate) fn parse_update(body: &[u8]) -> Result<Update, WebhookError> {
+    serde_json::from_slice(body).map_err(WebhookError::Json)
+}
+
+/// Compares the bytes without exiting early, so the time taken doesn't reveal how much of the token matched.
+/// Only the length, which isn't secret, is compared directly.
+fn constant_time_eq(expected: &[u8], actual: &[u8]) -> bool {
+    expected.len() == actual.len()
+        && expected
+            .iter()
+            .zip(actual)
+            .fold(0, |difference, (a, b)| difference | (a ^ b))
+            == 0
+}
@@ -0,0 +1,107 @@
+use apid_telegram_bot::{
+    types::{ApiResponse, ResponseParameters, User},
+    ApiError,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn deserialize_successful_response() {
+    let src = r#"
+        {
+            "ok": true,
+            "result": {
+                "id": 5589425236,
+                "is_bot": true,
+                "first_name": "apid",
+                "username": "apid_bot",
+                "can_join_groups": true,
+                "can_read_all_group_messages": false,
+                "supports_inline_queries": false
+            }
+        }
+    "#;
+    let response: ApiResponse<User> = serde_json::from_str(src).unwrap();
+    assert_eq!(
+        Ok(User {
+            id: 5589425236,
+            is_bot: true,
+            first_name: "apid".to_string(),
+            last_name: None,
+            username: Some("apid_bot".to_string()),
+            language_code: None,
+            is_premium: false,
+            added_to_attachment_menu: false,
+            can_join_groups: true,
+            can_read_all_group_messages: false,
+            supports_inline_queries: false,
+        }),
+        response.into_result(),
+    )
+}
+
+#[test]
+fn classify_flood_wait() {
+    let src = r#"
+        {
+            "ok": false,
+            "error_code": 429,
+            "description": "Too Many Requests: retry after 34",
+            "parameters": {
+                "retry_after": 34
+            }
+        }
+    "#;
+    let response: ApiResponse<User> = serde_json::from_str(src).unwrap();
+    let error = response.into_result().unwrap_err();
+    assert_eq!(
+        ApiError::TooManyRequests {
+            description: "Too Many Requests: retry after 34".to_string(),
+            retry_after: Some(34),
+        },
+        error,
+    );
+    assert_eq!(429, error.error_code());
+    assert_eq!(Some(34), error.retry_after());
+}
+
+#[test]
+fn classify_group_migration() {
+    let src = r#"
+        {
+            "ok": false,
+            "error_code": 400,
+            "description": "Bad Request: group chat was upgraded to a supergroup chat",
+            "parameters": {
+                "migrate_to_chat_id": -1001234567890
+            }
+        }
+    "#;
+    let response: ApiResponse<bool> = serde_json::from_str(src).unwrap();
+    let error = response.into_result().unwrap_err();
+    assert_eq!(
+        ApiError::MigrateToChat {
+            description: "Bad Request: group chat was upgraded to a supergroup chat".to_string(),
+            migrate_to_chat_id: -1001234567890,
+        },
+        error,
+    );
+    assert_eq!(Some(-1001234567890), error.migrate_to_chat_id());
+}
+
+#[test]
+fn classify_unknown_error() {
+    let response: ApiResponse<bool> = ApiResponse {
+        ok: false,
+        result: None,
+        description: Some("Internal Server Error".to_string()),
+        error_code: Some(500),
+        parameters: Some(ResponseParameters {
+            migrate_to_chat_id: None,
+            retry_after: Some(5),
+        }),
+    };
+    let error = response.into_result().unwrap_err();
+    assert_eq!(500, error.error_code());
+    assert_eq!(Some(5), error.retry_after());
+    assert_eq!("Internal Server Error (error code 500)", error.to_string());
+}
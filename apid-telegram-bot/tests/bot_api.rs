@@ -0,0 +1,133 @@
+use std::path::PathBuf;
+
+use apid::{Api, HttpMethod, HttpRequest, HttpResponse};
+use apid_telegram_bot::{
+    calls::{GetFile, GetMe, SendMessage},
+    types::ChatId,
+    ApiError, BotApi, Error, FileLocation,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn build_request() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let request = api
+        .request(&SendMessage {
+            chat_id: ChatId::Int(229584557),
+            text: "Hello, world!".to_string(),
+            parse_mode: None,
+            entities: vec![],
+            disable_web_page_preview: false,
+            disable_notification: false,
+            protect_content: false,
+            reply_to_message_id: None,
+            allow_sending_without_reply: false,
+            reply_markup: None,
+        })
+        .unwrap();
+    assert_eq!(
+        HttpRequest {
+            method: HttpMethod::Post,
+            url: "https://api.telegram.org/bot123456:ABC-DEF/sendMessage".to_string(),
+            headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+            body: br#"{"chat_id":229584557,"text":"Hello, world!"}"#.to_vec(),
+        },
+        request,
+    )
+}
+
+#[test]
+fn build_request_for_local_server() {
+    let api = BotApi::with_base_url("http://localhost:8081/", "123456:ABC-DEF");
+    assert_eq!(
+        "http://localhost:8081/bot123456:ABC-DEF/getMe",
+        api.request(&GetMe {}).unwrap().url,
+    )
+}
+
+#[test]
+fn decode_error_response() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let response = HttpResponse {
+        status: 401,
+        body: br#"{"ok":false,"error_code":401,"description":"Unauthorized"}"#.to_vec(),
+    };
+    match api.decode::<GetMe>(response) {
+        Err(Error::Api(error)) => assert_eq!(
+            ApiError::Unauthorized {
+                description: "Unauthorized".to_string()
+            },
+            error
+        ),
+        other => panic!("expected an API error, got {:?}", other),
+    }
+}
+
+#[test]
+fn decode_malformed_response() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let response = HttpResponse {
+        status: 502,
+        body: b"<html>Bad Gateway</html>".to_vec(),
+    };
+    assert!(matches!(
+        api.decode::<GetMe>(response),
+        Err(Error::Status(502))
+    ));
+
+    let response = HttpResponse {
+        status: 200,
+        body: b"<html>OK</html>".to_vec(),
+    };
+    assert!(matches!(api.decode::<GetMe>(response), Err(Error::Json(_))));
+}
+
+#[test]
+fn debug_hides_token() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let debug = format!("{:?}", api);
+    assert!(debug.contains("https://api.telegram.org"));
+    assert!(!debug.contains("ABC-DEF"));
+}
+
+#[test]
+fn file_location_on_cloud_server() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let file = api
+        .decode::<GetFile>(HttpResponse {
+            status: 200,
+            body: br#"{"ok":true,"result":{"file_id":"AgACAgUAAxkBAAIBY2","file_unique_id":"AQADsq0xG","file_size":48213,"file_path":"photos/file_0.jpg"}}"#.to_vec(),
+        })
+        .unwrap();
+    assert_eq!(
+        FileLocation::Url(
+            "https://api.telegram.org/file/bot123456:ABC-DEF/photos/file_0.jpg".to_string()
+        ),
+        api.file_location(file.file_path.as_deref().unwrap())
+    );
+}
+
+#[test]
+fn file_location_on_local_server() {
+    let api = BotApi::with_base_url("http://localhost:8081/", "123456:ABC-DEF");
+    assert_eq!(
+        "http://localhost:8081/file/bot123456:ABC-DEF/documents/file_1.pdf",
+        api.file_url("documents/file_1.pdf")
+    );
+    assert_eq!(
+        FileLocation::Local(PathBuf::from(
+            "/var/lib/telegram-bot-api/123456:ABC-DEF/documents/file_1.pdf"
+        )),
+        api.file_location("/var/lib/telegram-bot-api/123456:ABC-DEF/documents/file_1.pdf")
+    );
+}
+
+#[cfg(windows)]
+#[test]
+fn file_location_on_local_server_on_windows() {
+    let api = BotApi::with_base_url("http://localhost:8081/", "123456:ABC-DEF");
+    assert_eq!(
+        FileLocation::Local(PathBuf::from(r"C:\telegram-bot-api\documents\file_1.pdf")),
+        api.file_location(r"C:\telegram-bot-api\documents\file_1.pdf")
+    );
+}
@@ -0,0 +1,202 @@
+use apid::{Api, HttpResponse};
+use apid_telegram_bot::{
+    calls::{
+        DeleteMyCommands, GetChatMenuButton, GetMyCommands, GetMyDefaultAdministratorRights,
+        SetChatMenuButton, SetMyCommands, SetMyDefaultAdministratorRights,
+    },
+    types::{BotCommand, BotCommandScope, ChatAdministratorRights, ChatId, MenuButton, WebAppInfo},
+    BotApi,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn serialize_set_my_commands() {
+    let call = SetMyCommands {
+        commands: vec![
+            BotCommand {
+                command: "start".to_string(),
+                description: "Start the bot".to_string(),
+            },
+            BotCommand {
+                command: "ban".to_string(),
+                description: "Ban a member".to_string(),
+            },
+        ],
+        scope: Some(BotCommandScope::ChatAdministrators {
+            chat_id: ChatId::String("@apid".to_string()),
+        }),
+        language_code: Some("en".to_string()),
+    };
+    assert_eq!(
+        json!({
+            "commands": [
+                { "command": "start", "description": "Start the bot" },
+                { "command": "ban", "description": "Ban a member" }
+            ],
+            "scope": { "type": "chat_administrators", "chat_id": "@apid" },
+            "language_code": "en"
+        }),
+        serde_json::to_value(&call).unwrap()
+    );
+}
+
+#[test]
+fn serialize_command_scopes() {
+    let delete = DeleteMyCommands {
+        scope: Some(BotCommandScope::ChatMember {
+            chat_id: ChatId::Int(-1001234567890),
+            user_id: 229584557,
+        }),
+        language_code: None,
+    };
+    assert_eq!(
+        json!({
+            "scope": { "type": "chat_member", "chat_id": -1001234567890i64, "user_id": 229584557 }
+        }),
+        serde_json::to_value(&delete).unwrap()
+    );
+
+    let get = GetMyCommands {
+        scope: Some(BotCommandScope::AllPrivateChats),
+        language_code: Some("ko".to_string()),
+    };
+    assert_eq!(
+        json!({ "scope": { "type": "all_private_chats" }, "language_code": "ko" }),
+        serde_json::to_value(&get).unwrap()
+    );
+
+    let get = GetMyCommands {
+        scope: None,
+        language_code: None,
+    };
+    assert_eq!(json!({}), serde_json::to_value(&get).unwrap());
+}
+
+#[test]
+fn decode_my_commands() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let commands = api
+        .decode::<GetMyCommands>(HttpResponse {
+            status: 200,
+            body: br#"{"ok":true,"result":[{"command":"start","description":"Start the bot"}]}"#
+                .to_vec(),
+        })
+        .unwrap();
+    assert_eq!(
+        vec![BotCommand {
+            command: "start".to_string(),
+            description: "Start the bot".to_string(),
+        }],
+        commands
+    );
+}
+
+#[test]
+fn serialize_set_chat_menu_button() {
+    let call = SetChatMenuButton {
+        chat_id: Some(229584557),
+        menu_button: Some(MenuButton::WebApp {
+            text: "Open".to_string(),
+            web_app: WebAppInfo {
+                url: "https://apid.example.com/app".to_string(),
+            },
+        }),
+    };
+    assert_eq!(
+        json!({
+            "chat_id": 229584557,
+            "menu_button": {
+                "type": "web_app",
+                "text": "Open",
+                "web_app": { "url": "https://apid.example.com/app" }
+            }
+        }),
+        serde_json::to_value(&call).unwrap()
+    );
+
+    let call = SetChatMenuButton {
+        chat_id: None,
+        menu_button: Some(MenuButton::Commands),
+    };
+    assert_eq!(
+        json!({ "menu_button": { "type": "commands" } }),
+        serde_json::to_value(&call).unwrap()
+    );
+}
+
+#[test]
+fn decode_chat_menu_button() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let button = api
+        .decode::<GetChatMenuButton>(HttpResponse {
+            status: 200,
+            body: br#"{"ok":true,"result":{"type":"default"}}"#.to_vec(),
+        })
+        .unwrap();
+    assert_eq!(MenuButton::Default, button);
+}
+
+#[test]
+fn serialize_set_my_default_administrator_rights() {
+    let call = SetMyDefaultAdministratorRights {
+        rights: Some(ChatAdministratorRights {
+            can_manage_chat: true,
+            can_delete_messages: true,
+            can_pin_messages: true,
+            ..Default::default()
+        }),
+        for_channels: false,
+    };
+    assert_eq!(
+        json!({
+            "rights": {
+                "is_anonymous": false,
+                "can_manage_chat": true,
+                "can_delete_messages": true,
+                "can_manage_video_chats": false,
+                "can_restrict_members": false,
+                "can_promote_members": false,
+                "can_change_info": false,
+                "can_invite_users": false,
+                "can_pin_messages": true
+            }
+        }),
+        serde_json::to_value(&call).unwrap()
+    );
+
+    let call = SetMyDefaultAdministratorRights {
+        rights: None,
+        for_channels: true,
+    };
+    assert_eq!(
+        json!({ "for_channels": true }),
+        serde_json::to_value(&call).unwrap()
+    );
+}
+
+#[test]
+fn decode_my_default_administrator_rights() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let rights = api
+        .decode::<GetMyDefaultAdministratorRights>(HttpResponse {
+            status: 200,
+            body: br#"{"ok":true,"result":{"is_anonymous":false,"can_manage_chat":true,"can_delete_messages":false,"can_manage_video_chats":false,"can_restrict_members":false,"can_promote_members":false,"can_change_info":true,"can_invite_users":true,"can_post_messages":true,"can_edit_messages":true}}"#.to_vec(),
+        })
+        .unwrap();
+    assert_eq!(
+        ChatAdministratorRights {
+            can_manage_chat: true,
+            can_change_info: true,
+            can_invite_users: true,
+            can_post_messages: true,
+            can_edit_messages: true,
+            ..Default::default()
+        },
+        rights
+    );
+    assert_eq!(
+        json!({ "for_channels": true }),
+        serde_json::to_value(GetMyDefaultAdministratorRights { for_channels: true }).unwrap()
+    );
+}
@@ -0,0 +1,92 @@
+use apid::{Api, HttpResponse};
+use apid_telegram_bot::{
+    calls::{GetChatAdministrators, GetChatMemberCount, PinChatMessage, SetChatPhoto},
+    types::{ChatId, ChatMember, InputFileUpload},
+    BotApi,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn set_chat_photo_as_multipart() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let request = api
+        .request(&SetChatPhoto {
+            chat_id: ChatId::Int(-1001234567890),
+            photo: InputFileUpload::new("logo", "logo.jpg", b"JPEG".to_vec()),
+        })
+        .unwrap();
+    assert_eq!(
+        "https://api.telegram.org/bot123456:ABC-DEF/setChatPhoto",
+        request.url
+    );
+    assert_eq!(
+        concat!(
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"chat_id\"\r\n",
+            "\r\n",
+            "-1001234567890\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"photo\"\r\n",
+            "\r\n",
+            "attach://logo\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"logo\"; filename=\"logo.jpg\"\r\n",
+            "Content-Type: application/octet-stream\r\n",
+            "\r\n",
+            "JPEG\r\n",
+            "--apid-boundary-0--\r\n",
+        )
+        .as_bytes(),
+        request.body,
+    );
+}
+
+#[test]
+fn serialize_pin_chat_message() {
+    let call = PinChatMessage {
+        chat_id: ChatId::String("@apid".to_string()),
+        message_id: 1200,
+        disable_notification: true,
+    };
+    assert_eq!(
+        json!({ "chat_id": "@apid", "message_id": 1200, "disable_notification": true }),
+        serde_json::to_value(&call).unwrap()
+    );
+}
+
+#[test]
+fn decode_chat_member_responses() {
+    let api = BotApi::new("123456:ABC-DEF");
+
+    let administrators = api
+        .decode::<GetChatAdministrators>(HttpResponse {
+            status: 200,
+            body: json!({
+                "ok": true,
+                "result": [
+                    {
+                        "status": "creator",
+                        "user": { "id": 229584557, "is_bot": false, "first_name": "RanolP`22" },
+                        "is_anonymous": false,
+                        "custom_title": "owner"
+                    }
+                ]
+            })
+            .to_string()
+            .into_bytes(),
+        })
+        .unwrap();
+    assert!(matches!(
+        administrators.as_slice(),
+        [ChatMember::Owner { custom_title: Some(title), .. }] if title == "owner"
+    ));
+
+    let count = api
+        .decode::<GetChatMemberCount>(HttpResponse {
+            status: 200,
+            body: br#"{"ok":true,"result":42}"#.to_vec(),
+        })
+        .unwrap();
+    assert_eq!(42, count);
+}
@@ -0,0 +1,111 @@
+use apid::{Api, HttpResponse};
+use apid_telegram_bot::{
+    calls::{
+        ApproveChatJoinRequest, CreateChatInviteLink, EditChatInviteLink, ExportChatInviteLink,
+    },
+    types::{ChatId, Update, UpdateEvent},
+    BotApi,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn deserialize_chat_join_request() {
+    let src = json!({
+        "update_id": 721490400,
+        "chat_join_request": {
+            "chat": { "id": -1001234567890i64, "title": "apid", "type": "supergroup" },
+            "from": { "id": 229584557, "is_bot": false, "first_name": "RanolP`22" },
+            "date": 1660836000,
+            "bio": "Rustacean",
+            "invite_link": {
+                "invite_link": "https://t.me/+AbCdEf…",
+                "creator": { "id": 5432101234i64, "is_bot": true, "first_name": "ranol_bot" },
+                "creates_join_request": true,
+                "is_primary": false,
+                "is_revoked": false,
+                "pending_join_request_count": 3
+            }
+        }
+    });
+    let update: Update = serde_json::from_value(src).unwrap();
+    match update.event {
+        Some(UpdateEvent::ChatJoinRequest { chat_join_request }) => {
+            assert_eq!(229584557, chat_join_request.from.id);
+            assert_eq!(Some("Rustacean".to_string()), chat_join_request.bio);
+            let invite_link = chat_join_request.invite_link.unwrap();
+            assert!(invite_link.creates_join_request);
+            assert_eq!(Some(3), invite_link.pending_join_request_count);
+        }
+        event => panic!("expected a chat join request, got {:?}", event),
+    }
+}
+
+#[test]
+fn serialize_invite_link_calls() {
+    let create = CreateChatInviteLink {
+        chat_id: ChatId::String("@apid".to_string()),
+        name: Some("friends".to_string()),
+        expire_date: None,
+        member_limit: None,
+        creates_join_request: true,
+    };
+    assert_eq!(
+        json!({
+            "chat_id": "@apid",
+            "name": "friends",
+            "creates_join_request": true
+        }),
+        serde_json::to_value(&create).unwrap()
+    );
+
+    let edit = EditChatInviteLink {
+        chat_id: ChatId::Int(-1001234567890),
+        invite_link: "https://t.me/+AbCdEf".to_string(),
+        name: None,
+        expire_date: Some(1660922400),
+        member_limit: Some(10),
+        creates_join_request: false,
+    };
+    assert_eq!(
+        json!({
+            "chat_id": -1001234567890i64,
+            "invite_link": "https://t.me/+AbCdEf",
+            "expire_date": 1660922400,
+            "member_limit": 10
+        }),
+        serde_json::to_value(&edit).unwrap()
+    );
+
+    let approve = ApproveChatJoinRequest {
+        chat_id: ChatId::Int(-1001234567890),
+        user_id: 229584557,
+    };
+    assert_eq!(
+        json!({ "chat_id": -1001234567890i64, "user_id": 229584557 }),
+        serde_json::to_value(&approve).unwrap()
+    );
+}
+
+#[test]
+fn decode_invite_link_responses() {
+    let api = BotApi::new("123456:ABC-DEF");
+
+    let exported = api
+        .decode::<ExportChatInviteLink>(HttpResponse {
+            status: 200,
+            body: br#"{"ok":true,"result":"https://t.me/+XyZ"}"#.to_vec(),
+        })
+        .unwrap();
+    assert_eq!("https://t.me/+XyZ", exported);
+
+    let created = api
+        .decode::<CreateChatInviteLink>(HttpResponse {
+            status: 200,
+            body: br#"{"ok":true,"result":{"invite_link":"https://t.me/+AbCdEf","creator":{"id":5432101234,"is_bot":true,"first_name":"ranol_bot"},"creates_join_request":false,"is_primary":false,"is_revoked":false,"name":"friends"}}"#.to_vec(),
+        })
+        .unwrap();
+    assert_eq!("https://t.me/+AbCdEf", created.invite_link);
+    assert_eq!(Some("friends".to_string()), created.name);
+    assert!(created.creator.is_bot);
+}
@@ -0,0 +1,186 @@
+use apid_telegram_bot::types::{ChatMember, ChatMemberUpdated, Update, UpdateEvent};
+use pretty_assertions::assert_eq;
+use serde_json::{json, Value};
+
+fn user(id: i64, is_bot: bool) -> Value {
+    json!({
+        "id": id,
+        "is_bot": is_bot,
+        "first_name": if is_bot { "ranol_bot" } else { "RanolP`22" }
+    })
+}
+
+fn my_chat_member(chat: Value, old: Value, new: Value) -> ChatMemberUpdated {
+    let src = json!({
+        "update_id": 721490300,
+        "my_chat_member": {
+            "chat": chat,
+            "from": user(229584557, false),
+            "date": 1660836000,
+            "old_chat_member": old,
+            "new_chat_member": new
+        }
+    });
+    let update: Update = serde_json::from_value(src).unwrap();
+    match update.event {
+        Some(UpdateEvent::PrivateChatMemberUpdated { my_chat_member }) => my_chat_member,
+        event => panic!("expected a my_chat_member update, got {:?}", event),
+    }
+}
+
+fn group() -> Value {
+    json!({ "id": -1001234567890i64, "title": "apid", "type": "supergroup" })
+}
+
+fn private() -> Value {
+    json!({ "id": 229584557, "first_name": "RanolP`22", "type": "private" })
+}
+
+#[test]
+fn bot_added_to_group() {
+    let updated = my_chat_member(
+        group(),
+        json!({ "status": "left", "user": user(5432101234, true) }),
+        json!({ "status": "member", "user": user(5432101234, true) }),
+    );
+    assert!(updated.was_added());
+    assert!(!updated.was_kicked());
+    assert!(!updated.was_promoted());
+    assert!(!updated.was_blocked());
+}
+
+#[test]
+fn bot_promoted_in_group() {
+    let updated = my_chat_member(
+        group(),
+        json!({ "status": "member", "user": user(5432101234, true) }),
+        json!({
+            "status": "administrator",
+            "user": user(5432101234, true),
+            "can_be_edited": false,
+            "is_anonymous": false,
+            "can_manage_chat": true,
+            "can_delete_messages": true,
+            "can_manage_video_chats": false,
+            "can_restrict_members": true,
+            "can_promote_members": false,
+            "can_change_info": false,
+            "can_invite_users": true,
+            "can_pin_messages": true
+        }),
+    );
+    assert!(updated.was_promoted());
+    assert!(!updated.was_added());
+    assert!(matches!(
+        updated.new_chat_member,
+        ChatMember::Administrator {
+            can_restrict_members: true,
+            can_pin_messages: true,
+            can_post_messages: false,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn bot_kicked_from_group() {
+    let updated = my_chat_member(
+        group(),
+        json!({ "status": "member", "user": user(5432101234, true) }),
+        json!({ "status": "kicked", "user": user(5432101234, true), "until_date": 0 }),
+    );
+    assert!(updated.was_kicked());
+    assert!(!updated.was_blocked());
+}
+
+#[test]
+fn member_left_or_removed() {
+    let updated = |from: i64| -> ChatMemberUpdated {
+        serde_json::from_value(json!({
+            "chat": group(),
+            "from": user(from, false),
+            "date": 1660836000,
+            "old_chat_member": { "status": "member", "user": user(1234567, false) },
+            "new_chat_member": { "status": "left", "user": user(1234567, false) }
+        }))
+        .unwrap()
+    };
+    assert!(!updated(1234567).was_kicked());
+    assert!(updated(229584557).was_kicked());
+}
+
+#[test]
+fn bot_blocked_and_unblocked_by_user() {
+    let blocked = my_chat_member(
+        private(),
+        json!({ "status": "member", "user": user(5432101234, true) }),
+        json!({ "status": "kicked", "user": user(5432101234, true), "until_date": 0 }),
+    );
+    assert!(blocked.was_blocked());
+    assert!(!blocked.was_kicked());
+
+    let unblocked = my_chat_member(
+        private(),
+        json!({ "status": "kicked", "user": user(5432101234, true), "until_date": 0 }),
+        json!({ "status": "member", "user": user(5432101234, true) }),
+    );
+    assert!(unblocked.was_unblocked());
+    assert!(!unblocked.was_added());
+}
+
+#[test]
+fn chat_member_round_trip() {
+    let src = json!({
+        "update_id": 721490301,
+        "chat_member": {
+            "chat": group(),
+            "from": user(229584557, false),
+            "date": 1660836000,
+            "old_chat_member": { "status": "left", "user": user(1234567, false) },
+            "new_chat_member": {
+                "status": "restricted",
+                "user": user(1234567, false),
+                "is_member": true,
+                "can_change_info": false,
+                "can_invite_users": false,
+                "can_pin_messages": false,
+                "can_send_messages": true,
+                "can_send_media_messages": false,
+                "can_send_polls": false,
+                "can_send_other_messages": false,
+                "can_add_web_page_previews": false,
+                "until_date": 1660922400
+            },
+            "invite_link": {
+                "invite_link": "https://t.me/+AbCdEf…",
+                "creator": user(229584557, false),
+                "creates_join_request": false,
+                "is_primary": false,
+                "is_revoked": false,
+                "name": "friends",
+                "member_limit": 10
+            }
+        }
+    });
+    let update: Update = serde_json::from_value(src.clone()).unwrap();
+    match &update.event {
+        Some(UpdateEvent::ChatMemberUpdated { chat_member }) => {
+            assert_eq!(1234567, chat_member.new_chat_member.user().id);
+            assert!(chat_member.new_chat_member.is_present());
+            // The helpers look at the statuses only, so they apply to any user.
+            assert!(chat_member.was_added());
+            assert_eq!(
+                Some("friends"),
+                chat_member
+                    .invite_link
+                    .as_ref()
+                    .and_then(|link| link.name.as_deref())
+            );
+        }
+        event => panic!("expected a chat_member update, got {:?}", event),
+    }
+    assert_eq!(
+        src["chat_member"]["new_chat_member"],
+        serde_json::to_value(&update).unwrap()["chat_member"]["new_chat_member"]
+    );
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "reqwest")]
+
+use apid_telegram_bot::{
+    calls::{GetMe, SendMessage},
+    types::ChatId,
+    ApiError, Client, Error,
+};
+use pretty_assertions::assert_eq;
+use wiremock::{
+    matchers::{body_json, header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn call_get_me() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/bot123456:ABC-DEF/getMe"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"{"ok":true,"result":{"id":5589425236,"is_bot":true,"first_name":"apid","username":"apid_bot"}}"#,
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::with_base_url(server.uri(), "123456:ABC-DEF");
+    let me = client.call(&GetMe {}).await.unwrap();
+    assert_eq!(5589425236, me.id);
+    assert_eq!(Some("apid_bot".to_string()), me.username);
+}
+
+#[tokio::test]
+async fn call_send_message_with_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/bot123456:ABC-DEF/sendMessage"))
+        .and(header("Content-Type", "application/json"))
+        .and(body_json(serde_json::json!({
+            "chat_id": 229584557,
+            "text": "Hello, world!",
+        })))
+        .respond_with(ResponseTemplate::new(403).set_body_raw(
+            r#"{"ok":false,"error_code":403,"description":"Forbidden: bot was blocked by the user"}"#,
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::with_base_url(server.uri(), "123456:ABC-DEF");
+    let result = client
+        .call(&SendMessage {
+            chat_id: ChatId::Int(229584557),
+            text: "Hello, world!".to_string(),
+            parse_mode: None,
+            entities: vec![],
+            disable_web_page_preview: false,
+            disable_notification: false,
+            protect_content: false,
+            reply_to_message_id: None,
+            allow_sending_without_reply: false,
+            reply_markup: None,
+        })
+        .await;
+    match result {
+        Err(Error::Api(error)) => assert_eq!(
+            ApiError::Forbidden {
+                description: "Forbidden: bot was blocked by the user".to_string()
+            },
+            error
+        ),
+        other => panic!("expected an API error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn http_error_hides_token() {
+    // Nothing listens on the discard port, so the connection is refused.
+    let client = Client::with_base_url("http://127.0.0.1:9", "123456:ABC-DEF");
+    match client.call(&GetMe {}).await {
+        Err(error @ Error::Http(_)) => {
+            assert!(!error.to_string().contains("ABC-DEF"));
+            assert!(!format!("{:?}", error).contains("ABC-DEF"));
+        }
+        other => panic!("expected an HTTP error, got {:?}", other),
+    }
+}
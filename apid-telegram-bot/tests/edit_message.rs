@@ -0,0 +1,147 @@
+use apid::{Api, HttpResponse};
+use apid_telegram_bot::{
+    calls::{
+        DeleteMessage, EditMessageCaption, EditMessageMedia, EditMessageText, EditedMessage,
+        MessageTarget,
+    },
+    types::{Caption, ChatId, InputFile, InputMedia, InputMediaPhoto, MessageContent, ParseMode},
+    BotApi,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn serialize_edit_message_targets() {
+    let call = EditMessageText {
+        target: MessageTarget::Chat {
+            chat_id: ChatId::Int(229584557),
+            message_id: 1200,
+        },
+        text: "*edited*".to_string(),
+        parse_mode: Some(ParseMode::MarkdownV2),
+        entities: vec![],
+        disable_web_page_preview: false,
+        reply_markup: None,
+    };
+    assert_eq!(
+        json!({
+            "chat_id": 229584557,
+            "message_id": 1200,
+            "text": "*edited*",
+            "parse_mode": "MarkdownV2"
+        }),
+        serde_json::to_value(&call).unwrap()
+    );
+
+    let call = EditMessageCaption {
+        target: MessageTarget::Inline {
+            inline_message_id: "AQAAAGs3AAB".to_string(),
+        },
+        caption: Some(Caption {
+            text: "A cat".to_string(),
+            caption_entities: vec![],
+        }),
+        parse_mode: None,
+        reply_markup: None,
+    };
+    assert_eq!(
+        json!({ "inline_message_id": "AQAAAGs3AAB", "caption": "A cat" }),
+        serde_json::to_value(&call).unwrap()
+    );
+}
+
+#[test]
+fn edit_message_media_uploads_attachments() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let request = api
+        .request(&EditMessageMedia {
+            target: MessageTarget::Chat {
+                chat_id: ChatId::Int(229584557),
+                message_id: 1200,
+            },
+            media: InputMedia::Photo(InputMediaPhoto {
+                media: InputFile::upload("cat", "cat.png", b"PNG".to_vec()),
+                caption: None,
+                parse_mode: None,
+            }),
+            reply_markup: None,
+        })
+        .unwrap();
+    assert_eq!(
+        concat!(
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"chat_id\"\r\n",
+            "\r\n",
+            "229584557\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"media\"\r\n",
+            "\r\n",
+            "{\"media\":\"attach://cat\",\"type\":\"photo\"}\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"message_id\"\r\n",
+            "\r\n",
+            "1200\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"cat\"; filename=\"cat.png\"\r\n",
+            "Content-Type: application/octet-stream\r\n",
+            "\r\n",
+            "PNG\r\n",
+            "--apid-boundary-0--\r\n",
+        )
+        .as_bytes(),
+        request.body,
+    );
+}
+
+#[test]
+fn decode_edited_message() {
+    let api = BotApi::new("123456:ABC-DEF");
+
+    let edited = api
+        .decode::<EditMessageText>(HttpResponse {
+            status: 200,
+            body: json!({
+                "ok": true,
+                "result": {
+                    "message_id": 1200,
+                    "chat": { "id": 229584557, "first_name": "RanolP`22", "type": "private" },
+                    "date": 1660836000,
+                    "edit_date": 1660836060,
+                    "text": "edited"
+                }
+            })
+            .to_string()
+            .into_bytes(),
+        })
+        .unwrap();
+    match edited {
+        EditedMessage::Chat(message) => {
+            assert_eq!(1200, message.message_id);
+            assert!(matches!(message.content, MessageContent::Text { .. }));
+        }
+        other => panic!("expected an edited message, got {:?}", other),
+    }
+
+    let edited = api
+        .decode::<EditMessageText>(HttpResponse {
+            status: 200,
+            body: br#"{"ok":true,"result":true}"#.to_vec(),
+        })
+        .unwrap();
+    assert!(matches!(edited, EditedMessage::Inline(_)));
+}
+
+#[test]
+fn serialize_delete_message() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let request = api
+        .request(&DeleteMessage {
+            chat_id: ChatId::String("@apid".to_string()),
+            message_id: 1200,
+        })
+        .unwrap();
+    assert_eq!(
+        r#"{"chat_id":"@apid","message_id":1200}"#,
+        String::from_utf8(request.body).unwrap()
+    );
+}
@@ -0,0 +1,89 @@
+use apid::{Api, HttpResponse};
+use apid_telegram_bot::{
+    calls::{CopyMessage, ForwardMessage},
+    types::{
+        Caption, ChatId, InlineKeyboardButton, InlineKeyboardButtonAction, InlineKeyboardMarkup,
+        MessageEntity, MessageId,
+    },
+    BotApi,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn serialize_forward_message() {
+    let call = ForwardMessage {
+        chat_id: ChatId::String("@apid".to_string()),
+        from_chat_id: ChatId::Int(229584557),
+        disable_notification: true,
+        protect_content: false,
+        message_id: 1200,
+    };
+    assert_eq!(
+        json!({
+            "chat_id": "@apid",
+            "from_chat_id": 229584557,
+            "disable_notification": true,
+            "message_id": 1200
+        }),
+        serde_json::to_value(&call).unwrap()
+    );
+}
+
+#[test]
+fn serialize_copy_message_with_caption() {
+    let call = CopyMessage {
+        chat_id: ChatId::String("@apid".to_string()),
+        from_chat_id: ChatId::Int(229584557),
+        message_id: 1200,
+        caption: Some(Caption {
+            text: "Copied".to_string(),
+            caption_entities: serde_json::from_value::<Vec<MessageEntity>>(json!([
+                { "type": "bold", "offset": 0, "length": 6 }
+            ]))
+            .unwrap(),
+        }),
+        parse_mode: None,
+        disable_notification: false,
+        protect_content: true,
+        reply_to_message_id: None,
+        allow_sending_without_reply: false,
+        reply_markup: Some(
+            InlineKeyboardMarkup {
+                inline_keyboard: vec![vec![InlineKeyboardButton {
+                    text: "Original".to_string(),
+                    action: InlineKeyboardButtonAction::Url {
+                        url: "https://t.me/apid/1200".to_string(),
+                    },
+                }]],
+            }
+            .into(),
+        ),
+    };
+    assert_eq!(
+        json!({
+            "chat_id": "@apid",
+            "from_chat_id": 229584557,
+            "message_id": 1200,
+            "caption": "Copied",
+            "caption_entities": [{ "type": "bold", "offset": 0, "length": 6 }],
+            "protect_content": true,
+            "reply_markup": {
+                "inline_keyboard": [[{ "text": "Original", "url": "https://t.me/apid/1200" }]]
+            }
+        }),
+        serde_json::to_value(&call).unwrap()
+    );
+}
+
+#[test]
+fn decode_copy_message() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let message_id = api
+        .decode::<CopyMessage>(HttpResponse {
+            status: 200,
+            body: br#"{"ok":true,"result":{"message_id":1201}}"#.to_vec(),
+        })
+        .unwrap();
+    assert_eq!(MessageId { message_id: 1201 }, message_id);
+}
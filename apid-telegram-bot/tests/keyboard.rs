@@ -0,0 +1,179 @@
+use apid_telegram_bot::types::{
+    CallbackGame, ForceReply, InlineKeyboardButton, InlineKeyboardButtonAction,
+    InlineKeyboardMarkup, KeyboardButtonPollType, KeyboardButtonPollTypeKind, Keyboardbutton,
+    KeyboardbuttonAction, LoginUrl, Message, ReplyKeyboardMarkup, ReplyKeyboardRemove, ReplyMarkup,
+    True,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn deserialize_message_with_inline_keyboard() {
+    let src = r#"
+        {
+            "message_id": 18,
+            "from": {
+                "id": 5589425236,
+                "is_bot": true,
+                "first_name": "apid",
+                "username": "apid_bot"
+            },
+            "chat": {
+                "id": 229584557,
+                "first_name": "RanolP`22",
+                "username": "FunctionalInterface",
+                "type": "private"
+            },
+            "date": 1663491550,
+            "text": "Choose one",
+            "reply_markup": {
+                "inline_keyboard": [
+                    [
+                        { "text": "Yes", "callback_data": "yes" },
+                        { "text": "No", "callback_data": "no" }
+                    ],
+                    [
+                        { "text": "Docs", "url": "https://core.telegram.org/bots/api" }
+                    ]
+                ]
+            }
+        }
+    "#;
+    let message: Message = serde_json::from_str(src).unwrap();
+    assert_eq!(
+        Some(InlineKeyboardMarkup {
+            inline_keyboard: vec![
+                vec![
+                    InlineKeyboardButton {
+                        text: "Yes".to_string(),
+                        action: InlineKeyboardButtonAction::CallbackData {
+                            callback_data: "yes".to_string()
+                        },
+                    },
+                    InlineKeyboardButton {
+                        text: "No".to_string(),
+                        action: InlineKeyboardButtonAction::CallbackData {
+                            callback_data: "no".to_string()
+                        },
+                    },
+                ],
+                vec![InlineKeyboardButton {
+                    text: "Docs".to_string(),
+                    action: InlineKeyboardButtonAction::Url {
+                        url: "https://core.telegram.org/bots/api".to_string()
+                    },
+                }],
+            ],
+        }),
+        message.reply_markup,
+    );
+}
+
+#[test]
+fn serialize_inline_keyboard_buttons() {
+    let markup = ReplyMarkup::from(InlineKeyboardMarkup {
+        inline_keyboard: vec![vec![
+            InlineKeyboardButton {
+                text: "Play".to_string(),
+                action: InlineKeyboardButtonAction::CallbackGame {
+                    callback_game: CallbackGame {},
+                },
+            },
+            InlineKeyboardButton {
+                text: "Pay".to_string(),
+                action: InlineKeyboardButtonAction::Pay { pay: True },
+            },
+            InlineKeyboardButton {
+                text: "Log in".to_string(),
+                action: InlineKeyboardButtonAction::LoginUrl {
+                    login_url: LoginUrl {
+                        url: "https://example.com/login".to_string(),
+                        forward_text: None,
+                        bot_username: None,
+                        request_write_access: true,
+                    },
+                },
+            },
+            InlineKeyboardButton {
+                text: "Share".to_string(),
+                action: InlineKeyboardButtonAction::SwitchInlineQuery {
+                    switch_inline_query: String::new(),
+                },
+            },
+        ]],
+    });
+    assert_eq!(
+        json!({
+            "inline_keyboard": [[
+                { "text": "Play", "callback_game": {} },
+                { "text": "Pay", "pay": true },
+                {
+                    "text": "Log in",
+                    "login_url": {
+                        "url": "https://example.com/login",
+                        "request_write_access": true,
+                    },
+                },
+                { "text": "Share", "switch_inline_query": "" },
+            ]],
+        }),
+        serde_json::to_value(&markup).unwrap(),
+    );
+}
+
+#[test]
+fn serialize_reply_keyboard() {
+    let markup = ReplyMarkup::from(ReplyKeyboardMarkup {
+        keyboard: vec![
+            vec![Keyboardbutton {
+                text: "Hello".to_string(),
+                action: None,
+            }],
+            vec![Keyboardbutton {
+                text: "Create a quiz".to_string(),
+                action: Some(KeyboardbuttonAction::RequestPoll {
+                    request_poll: KeyboardButtonPollType {
+                        kind: Some(KeyboardButtonPollTypeKind::Quiz),
+                    },
+                }),
+            }],
+        ],
+        resize_keyboard: true,
+        one_time_keyboard: false,
+        input_field_placeholder: Some("Say hello".to_string()),
+        selective: false,
+    });
+    assert_eq!(
+        json!({
+            "keyboard": [
+                [{ "text": "Hello" }],
+                [{ "text": "Create a quiz", "request_poll": { "type": "quiz" } }],
+            ],
+            "resize_keyboard": true,
+            "input_field_placeholder": "Say hello",
+        }),
+        serde_json::to_value(&markup).unwrap(),
+    );
+}
+
+#[test]
+fn round_trip_reply_markup() {
+    let remove = json!({ "remove_keyboard": true, "selective": true });
+    assert_eq!(
+        ReplyMarkup::ReplyKeyboardRemove(ReplyKeyboardRemove {
+            remove_keyboard: True,
+            selective: true,
+        }),
+        serde_json::from_value(remove).unwrap(),
+    );
+
+    let force_reply = json!({ "force_reply": true, "input_field_placeholder": "Your name" });
+    assert_eq!(
+        ReplyMarkup::ForceReply(ForceReply {
+            force_reply: True,
+            input_field_placeholder: Some("Your name".to_string()),
+            selective: false,
+        }),
+        serde_json::from_value(force_reply).unwrap(),
+    );
+}
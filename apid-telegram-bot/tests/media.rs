@@ -0,0 +1,264 @@
+use apid_telegram_bot::types::{
+    Animation, Audio, Caption, Document, Message, MessageContent, PhotoSize, Video, VideoNote,
+    Voice,
+};
+use pretty_assertions::assert_eq;
+
+fn message_with(content: &str) -> Message {
+    let src = format!(
+        r#"
+        {{
+            "message_id": 27,
+            "from": {{
+                "id": 229584557,
+                "is_bot": false,
+                "first_name": "RanolP`22",
+                "username": "FunctionalInterface",
+                "language_code": "en"
+            }},
+            "chat": {{
+                "id": 229584557,
+                "first_name": "RanolP`22",
+                "username": "FunctionalInterface",
+                "type": "private"
+            }},
+            "date": 1663491550,
+            {}
+        }}
+        "#,
+        content
+    );
+    serde_json::from_str(&src).unwrap()
+}
+
+#[test]
+fn deserialize_voice() {
+    let message = message_with(
+        r#"
+            "voice": {
+                "duration": 2,
+                "mime_type": "audio/ogg",
+                "file_id": "AwACAgUAAxkBAAMbYyfL3Vqm0fVO7DiwwJm4_mRQYJ0AAj8HAAJe9TlV4uDf3Zad9ZcpBA",
+                "file_unique_id": "AgADPwcAAl71OVU",
+                "file_size": 8473
+            }
+        "#,
+    );
+    assert_eq!(
+        MessageContent::Voice {
+            voice: Voice {
+                file_id: "AwACAgUAAxkBAAMbYyfL3Vqm0fVO7DiwwJm4_mRQYJ0AAj8HAAJe9TlV4uDf3Zad9ZcpBA"
+                    .to_string(),
+                file_unique_id: "AgADPwcAAl71OVU".to_string(),
+                duration: 2,
+                mime_type: Some("audio/ogg".to_string()),
+                file_size: Some(8473),
+            },
+            caption: None,
+        },
+        message.content,
+    );
+}
+
+#[test]
+fn deserialize_document_with_caption() {
+    let message = message_with(
+        r#"
+            "document": {
+                "file_name": "report.pdf",
+                "mime_type": "application/pdf",
+                "thumb": {
+                    "file_id": "AAMCBQADGQEAAxxjJ8vzAAFBdDOsrkoovq0cGhGeMVsAAkAHAAJe9TlVK-BrmpxzQ5gBAAdtAAMpBA",
+                    "file_unique_id": "AQADQAcAAl71OVVy",
+                    "file_size": 11393,
+                    "width": 226,
+                    "height": 320
+                },
+                "file_id": "BQACAgUAAxkBAAMcYyfL8wABQXQzrK5KKL6tHBoRnjFbAAJABwACXvU5VSvga5qcc0OYKQQ",
+                "file_unique_id": "AgADQAcAAl71OVU",
+                "file_size": 172391
+            },
+            "caption": "Monthly report"
+        "#,
+    );
+    assert_eq!(
+        MessageContent::Document {
+            document: Document {
+                file_id: "BQACAgUAAxkBAAMcYyfL8wABQXQzrK5KKL6tHBoRnjFbAAJABwACXvU5VSvga5qcc0OYKQQ"
+                    .to_string(),
+                file_unique_id: "AgADQAcAAl71OVU".to_string(),
+                thumb: Some(PhotoSize {
+                    file_id:
+                        "AAMCBQADGQEAAxxjJ8vzAAFBdDOsrkoovq0cGhGeMVsAAkAHAAJe9TlVK-BrmpxzQ5gBAAdtAAMpBA"
+                            .to_string(),
+                    file_unique_id: "AQADQAcAAl71OVVy".to_string(),
+                    width: 226,
+                    height: 320,
+                    file_size: Some(11393),
+                }),
+                file_name: Some("report.pdf".to_string()),
+                mime_type: Some("application/pdf".to_string()),
+                file_size: Some(172391),
+            },
+            caption: Some(Caption {
+                text: "Monthly report".to_string(),
+                caption_entities: vec![],
+            }),
+        },
+        message.content,
+    );
+}
+
+#[test]
+fn deserialize_audio() {
+    let message = message_with(
+        r#"
+            "audio": {
+                "duration": 184,
+                "file_name": "song.mp3",
+                "mime_type": "audio/mpeg",
+                "title": "Song",
+                "performer": "Artist",
+                "file_id": "CQACAgUAAxkBAAMdYyfMEiBzBQmPpVwDM3AAAdEAAbnVbAACQQcAAl71OVUN8ZC9AAEWqM4pBA",
+                "file_unique_id": "AgADQQcAAl71OVU",
+                "file_size": 4435617
+            }
+        "#,
+    );
+    assert_eq!(
+        MessageContent::Audio {
+            audio: Audio {
+                file_id:
+                    "CQACAgUAAxkBAAMdYyfMEiBzBQmPpVwDM3AAAdEAAbnVbAACQQcAAl71OVUN8ZC9AAEWqM4pBA"
+                        .to_string(),
+                file_unique_id: "AgADQQcAAl71OVU".to_string(),
+                duration: 184,
+                performer: Some("Artist".to_string()),
+                title: Some("Song".to_string()),
+                file_name: Some("song.mp3".to_string()),
+                mime_type: Some("audio/mpeg".to_string()),
+                file_size: Some(4435617),
+                thumb: None,
+            },
+            caption: None,
+        },
+        message.content,
+    );
+}
+
+#[test]
+fn deserialize_video() {
+    let message = message_with(
+        r#"
+            "video": {
+                "duration": 5,
+                "width": 1280,
+                "height": 720,
+                "file_name": "clip.mp4",
+                "mime_type": "video/mp4",
+                "file_id": "BAACAgUAAxkBAAMeYyfMMNAhKzyTLn8bQmSrqGnTSgADQgcAAl71OVVHAAGp3aOujCkE",
+                "file_unique_id": "AgADQgcAAl71OVU",
+                "file_size": 3088931
+            }
+        "#,
+    );
+    assert_eq!(
+        MessageContent::Video {
+            video: Video {
+                file_id: "BAACAgUAAxkBAAMeYyfMMNAhKzyTLn8bQmSrqGnTSgADQgcAAl71OVVHAAGp3aOujCkE"
+                    .to_string(),
+                file_unique_id: "AgADQgcAAl71OVU".to_string(),
+                width: 1280,
+                height: 720,
+                duration: 5,
+                thumb: None,
+                file_name: Some("clip.mp4".to_string()),
+                mime_type: Some("video/mp4".to_string()),
+                file_size: Some(3088931),
+            },
+            caption: None,
+        },
+        message.content,
+    );
+}
+
+#[test]
+fn deserialize_video_note() {
+    let message = message_with(
+        r#"
+            "video_note": {
+                "duration": 3,
+                "length": 384,
+                "file_id": "DQACAgUAAxkBAAMfYyfMWSeIDAuFLPcNLZ3NoaPHy5MAAkMHAAJe9TlVbEB1sWIvDvopBA",
+                "file_unique_id": "AgADQwcAAl71OVU",
+                "file_size": 302412
+            }
+        "#,
+    );
+    assert_eq!(
+        MessageContent::VideoNote {
+            video_note: VideoNote {
+                file_id: "DQACAgUAAxkBAAMfYyfMWSeIDAuFLPcNLZ3NoaPHy5MAAkMHAAJe9TlVbEB1sWIvDvopBA"
+                    .to_string(),
+                file_unique_id: "AgADQwcAAl71OVU".to_string(),
+                length: 384,
+                duration: 3,
+                thumb: None,
+                file_size: Some(302412),
+            },
+        },
+        message.content,
+    );
+}
+
+#[test]
+fn deserialize_animation_with_document() {
+    let message = message_with(
+        r#"
+            "animation": {
+                "file_name": "cat.mp4",
+                "mime_type": "video/mp4",
+                "duration": 2,
+                "width": 320,
+                "height": 240,
+                "file_id": "CgACAgUAAxkBAAMgYyfMfUsqcSP6ScAAAU9H6dbS6l8AAkQHAAJe9TlVVnAfIdWVlikpBA",
+                "file_unique_id": "AgADRAcAAl71OVU",
+                "file_size": 52131
+            },
+            "document": {
+                "file_name": "cat.mp4",
+                "mime_type": "video/mp4",
+                "file_id": "CgACAgUAAxkBAAMgYyfMfUsqcSP6ScAAAU9H6dbS6l8AAkQHAAJe9TlVVnAfIdWVlikpBA",
+                "file_unique_id": "AgADRAcAAl71OVU",
+                "file_size": 52131
+            }
+        "#,
+    );
+    assert_eq!(
+        MessageContent::Animation {
+            animation: Animation {
+                file_id: "CgACAgUAAxkBAAMgYyfMfUsqcSP6ScAAAU9H6dbS6l8AAkQHAAJe9TlVVnAfIdWVlikpBA"
+                    .to_string(),
+                file_unique_id: "AgADRAcAAl71OVU".to_string(),
+                width: 320,
+                height: 240,
+                duration: 2,
+                thumb: None,
+                file_name: Some("cat.mp4".to_string()),
+                mime_type: Some("video/mp4".to_string()),
+                file_size: Some(52131),
+            },
+            document: Document {
+                file_id: "CgACAgUAAxkBAAMgYyfMfUsqcSP6ScAAAU9H6dbS6l8AAkQHAAJe9TlVVnAfIdWVlikpBA"
+                    .to_string(),
+                file_unique_id: "AgADRAcAAl71OVU".to_string(),
+                thumb: None,
+                file_name: Some("cat.mp4".to_string()),
+                mime_type: Some("video/mp4".to_string()),
+                file_size: Some(52131),
+            },
+            caption: None,
+        },
+        message.content,
+    );
+}
@@ -0,0 +1,160 @@
+use apid::Api;
+use apid_telegram_bot::{
+    calls::{MediaGroup, MediaGroupError, SendMediaGroup},
+    types::{
+        Caption, ChatId, InputFile, InputFileUpload, InputMedia, InputMediaAnimation,
+        InputMediaDocument, InputMediaPhoto, InputMediaVideo,
+    },
+    BotApi,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+fn photo(media: InputFile) -> InputMedia {
+    InputMedia::Photo(InputMediaPhoto {
+        media,
+        caption: None,
+        parse_mode: None,
+    })
+}
+
+fn video(media: InputFile, thumb: Option<InputFileUpload>) -> InputMedia {
+    InputMedia::Video(InputMediaVideo {
+        media,
+        thumb,
+        caption: None,
+        parse_mode: None,
+        width: None,
+        height: None,
+        duration: None,
+        supports_streaming: false,
+    })
+}
+
+fn document(media: InputFile) -> InputMedia {
+    InputMedia::Document(InputMediaDocument {
+        media,
+        thumb: None,
+        caption: None,
+        parse_mode: None,
+        disable_content_type_detection: false,
+    })
+}
+
+fn file_id(id: &str) -> InputFile {
+    InputFile::FileId(id.to_string())
+}
+
+#[test]
+fn validate_media_group_length() {
+    assert_eq!(
+        Err(MediaGroupError::InvalidLength(1)),
+        MediaGroup::new(vec![photo(file_id("a"))])
+    );
+    assert_eq!(
+        Err(MediaGroupError::InvalidLength(11)),
+        MediaGroup::new((0..11).map(|_| photo(file_id("a"))).collect())
+    );
+    assert!(MediaGroup::new((0..10).map(|_| photo(file_id("a"))).collect()).is_ok());
+}
+
+#[test]
+fn validate_media_group_kinds() {
+    assert!(MediaGroup::new(vec![photo(file_id("a")), video(file_id("b"), None)]).is_ok());
+    assert!(MediaGroup::new(vec![document(file_id("a")), document(file_id("b"))]).is_ok());
+    assert_eq!(
+        Err(MediaGroupError::IncompatibleMedia),
+        MediaGroup::new(vec![photo(file_id("a")), document(file_id("b"))])
+    );
+    assert_eq!(
+        Err(MediaGroupError::Animation),
+        MediaGroup::new(vec![
+            photo(file_id("a")),
+            InputMedia::Animation(InputMediaAnimation {
+                media: file_id("b"),
+                thumb: None,
+                caption: None,
+                parse_mode: None,
+                width: None,
+                height: None,
+                duration: None,
+            }),
+        ])
+    );
+}
+
+#[test]
+fn validate_unique_attachment_names() {
+    assert_eq!(
+        Err(MediaGroupError::DuplicateAttachment("cat".to_string())),
+        MediaGroup::new(vec![
+            photo(InputFile::upload("cat", "cat.png", b"PNG".to_vec())),
+            video(
+                InputFile::upload("clip", "clip.mp4", b"MP4".to_vec()),
+                Some(InputFileUpload::new("cat", "thumb.jpg", b"JPEG".to_vec())),
+            ),
+        ])
+    );
+}
+
+#[test]
+fn reject_invalid_media_group_on_deserialize() {
+    let result = serde_json::from_value::<MediaGroup>(json!([
+        { "type": "photo", "media": "AgACAgUAAxkBAAIBY2" }
+    ]));
+    assert!(result.is_err());
+}
+
+#[test]
+fn send_media_group_as_multipart() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let request = api
+        .request(&SendMediaGroup {
+            chat_id: ChatId::Int(229584557),
+            media: MediaGroup::new(vec![
+                InputMedia::Photo(InputMediaPhoto {
+                    media: InputFile::upload("cat", "cat.png", b"PNG".to_vec()),
+                    caption: Some(Caption {
+                        text: "Album".to_string(),
+                        caption_entities: vec![],
+                    }),
+                    parse_mode: None,
+                }),
+                video(
+                    file_id("BAACAgUAAxkBAAIBZ2"),
+                    Some(InputFileUpload::new("thumb", "thumb.jpg", b"JPEG".to_vec())),
+                ),
+            ])
+            .unwrap(),
+            disable_notification: false,
+            protect_content: false,
+            reply_to_message_id: None,
+            allow_sending_without_reply: false,
+        })
+        .unwrap();
+    assert_eq!(
+        concat!(
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"chat_id\"\r\n",
+            "\r\n",
+            "229584557\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"media\"\r\n",
+            "\r\n",
+            "[{\"caption\":\"Album\",\"media\":\"attach://cat\",\"type\":\"photo\"},",
+            "{\"media\":\"BAACAgUAAxkBAAIBZ2\",\"thumb\":\"attach://thumb\",\"type\":\"video\"}]\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"cat\"; filename=\"cat.png\"\r\n",
+            "Content-Type: application/octet-stream\r\n",
+            "\r\n",
+            "PNG\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"thumb\"; filename=\"thumb.jpg\"\r\n",
+            "Content-Type: application/octet-stream\r\n",
+            "\r\n",
+            "JPEG\r\n",
+            "--apid-boundary-0--\r\n",
+        ),
+        String::from_utf8(request.body).unwrap()
+    );
+}
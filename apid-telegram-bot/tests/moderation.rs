@@ -0,0 +1,111 @@
+use apid_telegram_bot::{
+    calls::{BanChatMember, PromoteChatMember, RestrictChatMember, SetChatPermissions},
+    types::{Chat, ChatId, ChatPermissions},
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn deserialize_chat_permissions() {
+    let chat: Chat = serde_json::from_value(json!({
+        "id": -1001234567890i64,
+        "title": "apid",
+        "type": "supergroup",
+        "permissions": {
+            "can_send_messages": true,
+            "can_send_media_messages": true,
+            "can_send_polls": false,
+            "can_send_other_messages": false,
+            "can_add_web_page_previews": true,
+            "can_change_info": false,
+            "can_invite_users": true,
+            "can_pin_messages": false
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        Some(ChatPermissions {
+            can_send_messages: true,
+            can_send_media_messages: true,
+            can_add_web_page_previews: true,
+            can_invite_users: true,
+            ..Default::default()
+        }),
+        chat.permissions
+    );
+}
+
+#[test]
+fn serialize_restrict_chat_member() {
+    let call = RestrictChatMember {
+        chat_id: ChatId::String("@apid".to_string()),
+        user_id: 229584557,
+        permissions: ChatPermissions {
+            can_send_messages: true,
+            ..Default::default()
+        },
+        until_date: Some(1660922400),
+    };
+    assert_eq!(
+        json!({
+            "chat_id": "@apid",
+            "user_id": 229584557,
+            "permissions": { "can_send_messages": true },
+            "until_date": 1660922400
+        }),
+        serde_json::to_value(&call).unwrap()
+    );
+
+    let call = SetChatPermissions {
+        chat_id: ChatId::Int(-1001234567890),
+        permissions: ChatPermissions::default(),
+    };
+    assert_eq!(
+        json!({ "chat_id": -1001234567890i64, "permissions": {} }),
+        serde_json::to_value(&call).unwrap()
+    );
+}
+
+#[test]
+fn serialize_ban_and_promote() {
+    let call = BanChatMember {
+        chat_id: ChatId::Int(-1001234567890),
+        user_id: 229584557,
+        until_date: None,
+        revoke_messages: true,
+    };
+    assert_eq!(
+        json!({
+            "chat_id": -1001234567890i64,
+            "user_id": 229584557,
+            "revoke_messages": true
+        }),
+        serde_json::to_value(&call).unwrap()
+    );
+
+    let call = PromoteChatMember {
+        chat_id: ChatId::Int(-1001234567890),
+        user_id: 229584557,
+        is_anonymous: false,
+        can_manage_chat: true,
+        can_post_messages: false,
+        can_edit_messages: false,
+        can_delete_messages: true,
+        can_manage_video_chats: false,
+        can_restrict_members: false,
+        can_promote_members: false,
+        can_change_info: false,
+        can_invite_users: false,
+        can_pin_messages: true,
+    };
+    assert_eq!(
+        json!({
+            "chat_id": -1001234567890i64,
+            "user_id": 229584557,
+            "can_manage_chat": true,
+            "can_delete_messages": true,
+            "can_pin_messages": true
+        }),
+        serde_json::to_value(&call).unwrap()
+    );
+}
@@ -0,0 +1,109 @@
+use apid::{Api, Attachment, Call};
+use apid_telegram_bot::{
+    types::{ChatId, InputFile, Message},
+    BotApi,
+};
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SendPhoto {
+    chat_id: ChatId,
+    photo: InputFile,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+}
+
+impl Call for SendPhoto {
+    type Response = Message;
+    const NAME: &'static str = "sendPhoto";
+
+    fn attachments(&self) -> Vec<Attachment<'_>> {
+        self.photo.attachment().into_iter().collect()
+    }
+}
+
+#[test]
+fn send_file_id_as_json() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let request = api
+        .request(&SendPhoto {
+            chat_id: ChatId::Int(229584557),
+            photo: InputFile::FileId("AgACAgUAAxkBAAIBY2".to_string()),
+            caption: None,
+        })
+        .unwrap();
+    assert_eq!(
+        vec![("Content-Type".to_string(), "application/json".to_string())],
+        request.headers,
+    );
+    assert_eq!(
+        r#"{"chat_id":229584557,"photo":"AgACAgUAAxkBAAIBY2"}"#,
+        String::from_utf8(request.body).unwrap(),
+    );
+}
+
+#[test]
+fn send_upload_as_multipart() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let request = api
+        .request(&SendPhoto {
+            chat_id: ChatId::Int(229584557),
+            photo: InputFile::upload("cat", "cat.png", b"PNG".to_vec()),
+            caption: Some("A cat".to_string()),
+        })
+        .unwrap();
+    assert_eq!(
+        vec![(
+            "Content-Type".to_string(),
+            "multipart/form-data; boundary=apid-boundary-0".to_string()
+        )],
+        request.headers,
+    );
+    assert_eq!(
+        concat!(
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"caption\"\r\n",
+            "\r\n",
+            "A cat\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"chat_id\"\r\n",
+            "\r\n",
+            "229584557\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"photo\"\r\n",
+            "\r\n",
+            "attach://cat\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"cat\"; filename=\"cat.png\"\r\n",
+            "Content-Type: application/octet-stream\r\n",
+            "\r\n",
+            "PNG\r\n",
+            "--apid-boundary-0--\r\n",
+        )
+        .as_bytes(),
+        request.body,
+    );
+}
+
+#[test]
+fn reject_attachment_named_as_field() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let error = api
+        .request(&SendPhoto {
+            chat_id: ChatId::Int(229584557),
+            photo: InputFile::upload("chat_id", "cat.png", b"PNG".to_vec()),
+            caption: None,
+        })
+        .unwrap_err();
+    assert_eq!(
+        "attachment `chat_id` is named the same as a field",
+        error.to_string()
+    );
+}
+
+#[test]
+fn upload_buffered_from_reader() {
+    let file = InputFile::upload_buffered("cat", "cat.png", &b"PNG"[..]).unwrap();
+    assert_eq!(InputFile::upload("cat", "cat.png", b"PNG".to_vec()), file);
+}
@@ -0,0 +1,201 @@
+use apid_telegram_bot::{
+    calls::{
+        AnswerPreCheckoutQuery, AnswerShippingQuery, PreCheckoutQueryAnswer, ShippingQueryAnswer,
+    },
+    types::{
+        False, LabeledPrice, MessageContent, OrderInfo, PreCheckoutQuery, ShippingAddress,
+        ShippingOption, SuccessfulPayment, True, Update, UpdateEvent,
+    },
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+const FROM: &str = r#"
+    "from": {
+        "id": 229584557,
+        "is_bot": false,
+        "first_name": "RanolP`22",
+        "username": "FunctionalInterface",
+        "language_code": "en"
+    }
+"#;
+
+#[test]
+fn deserialize_shipping_query() {
+    let src = format!(
+        r#"
+        {{
+            "update_id": 721490261,
+            "shipping_query": {{
+                "id": "986054451539823645",
+                {},
+                "invoice_payload": "order-42",
+                "shipping_address": {{
+                    "country_code": "KR",
+                    "state": "",
+                    "city": "Seoul",
+                    "street_line1": "1 Sejong-daero",
+                    "street_line2": "",
+                    "post_code": "04524"
+                }}
+            }}
+        }}
+        "#,
+        FROM
+    );
+    let update: Update = serde_json::from_str(&src).unwrap();
+    match update.event {
+        Some(UpdateEvent::ShippingQuery { shipping_query }) => {
+            assert_eq!("986054451539823645", shipping_query.id);
+            assert_eq!("order-42", shipping_query.invoice_payload);
+            assert_eq!(
+                ShippingAddress {
+                    country_code: "KR".to_string(),
+                    state: String::new(),
+                    city: "Seoul".to_string(),
+                    street_line1: "1 Sejong-daero".to_string(),
+                    street_line2: String::new(),
+                    post_code: "04524".to_string(),
+                },
+                shipping_query.shipping_address,
+            );
+        }
+        event => panic!("expected a shipping query, got {:?}", event),
+    }
+}
+
+#[test]
+fn deserialize_pre_checkout_query() {
+    let src = format!(
+        r#"
+        {{
+            "update_id": 721490262,
+            "pre_checkout_query": {{
+                "id": "986054452114026781",
+                {},
+                "currency": "USD",
+                "total_amount": 1450,
+                "invoice_payload": "order-42",
+                "shipping_option_id": "express",
+                "order_info": {{
+                    "name": "RanolP"
+                }}
+            }}
+        }}
+        "#,
+        FROM
+    );
+    let update: Update = serde_json::from_str(&src).unwrap();
+    match update.event {
+        Some(UpdateEvent::PreCheckoutQuery { pre_checkout_query }) => {
+            let PreCheckoutQuery {
+                currency,
+                total_amount,
+                shipping_option_id,
+                order_info,
+                ..
+            } = pre_checkout_query;
+            assert_eq!("USD", currency);
+            assert_eq!(1450, total_amount);
+            assert_eq!(Some("express".to_string()), shipping_option_id);
+            assert_eq!(
+                Some(OrderInfo {
+                    name: Some("RanolP".to_string()),
+                    phone_number: None,
+                    email: None,
+                    shipping_address: None,
+                }),
+                order_info,
+            );
+        }
+        event => panic!("expected a pre-checkout query, got {:?}", event),
+    }
+}
+
+#[test]
+fn deserialize_successful_payment() {
+    let src = format!(
+        r#"
+        {{
+            "message_id": 31,
+            {},
+            "chat": {{
+                "id": 229584557,
+                "first_name": "RanolP`22",
+                "username": "FunctionalInterface",
+                "type": "private"
+            }},
+            "date": 1663491550,
+            "successful_payment": {{
+                "currency": "USD",
+                "total_amount": 1450,
+                "invoice_payload": "order-42",
+                "telegram_payment_charge_id": "2209054441_229584557_31",
+                "provider_payment_charge_id": "ch_3LjL7RKsGjSYhHsT0pW1yRz9"
+            }}
+        }}
+        "#,
+        FROM
+    );
+    let message: apid_telegram_bot::types::Message = serde_json::from_str(&src).unwrap();
+    assert_eq!(
+        MessageContent::SuccessfulPayment {
+            successful_payment: SuccessfulPayment {
+                currency: "USD".to_string(),
+                total_amount: 1450,
+                invoice_payload: "order-42".to_string(),
+                shipping_option_id: None,
+                order_info: None,
+                telegram_payment_charge_id: "2209054441_229584557_31".to_string(),
+                provider_payment_charge_id: "ch_3LjL7RKsGjSYhHsT0pW1yRz9".to_string(),
+            }
+        },
+        message.content,
+    );
+}
+
+#[test]
+fn serialize_answers() {
+    let call = AnswerShippingQuery {
+        shipping_query_id: "986054451539823645".to_string(),
+        answer: ShippingQueryAnswer::Ok {
+            ok: True,
+            shipping_options: vec![ShippingOption {
+                id: "express".to_string(),
+                title: "Express".to_string(),
+                prices: vec![LabeledPrice {
+                    label: "Delivery".to_string(),
+                    amount: 450,
+                }],
+            }],
+        },
+    };
+    assert_eq!(
+        json!({
+            "shipping_query_id": "986054451539823645",
+            "ok": true,
+            "shipping_options": [{
+                "id": "express",
+                "title": "Express",
+                "prices": [{ "label": "Delivery", "amount": 450 }],
+            }],
+        }),
+        serde_json::to_value(&call).unwrap(),
+    );
+
+    let call = AnswerPreCheckoutQuery {
+        pre_checkout_query_id: "986054452114026781".to_string(),
+        answer: PreCheckoutQueryAnswer::Err {
+            ok: False,
+            error_message: "Sold out".to_string(),
+        },
+    };
+    assert_eq!(
+        json!({
+            "pre_checkout_query_id": "986054452114026781",
+            "ok": false,
+            "error_message": "Sold out",
+        }),
+        serde_json::to_value(&call).unwrap(),
+    );
+}
@@ -0,0 +1,273 @@
+#![cfg(feature = "polling")]
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use apid::{HttpRequest, HttpResponse};
+use apid_telegram_bot::{
+    types::{Update, UpdateKind},
+    ApiError, BotApi, Error, Polling, Transport, TransportFuture,
+};
+use futures_util::StreamExt;
+use pretty_assertions::assert_eq;
+use serde_json::{json, Value};
+use tokio::time::Instant;
+
+/// Answers each request with the next scripted response and records the request bodies.
+#[derive(Debug, Clone, Default)]
+struct ScriptedTransport {
+    responses: Arc<Mutex<VecDeque<HttpResponse>>>,
+    requests: Arc<Mutex<Vec<Value>>>,
+}
+
+impl ScriptedTransport {
+    fn new(responses: impl IntoIterator<Item = (u16, Value)>) -> Self {
+        let transport = ScriptedTransport::default();
+        for (status, body) in responses {
+            transport.push(status, body);
+        }
+        transport
+    }
+
+    fn push(&self, status: u16, body: Value) {
+        self.responses.lock().unwrap().push_back(HttpResponse {
+            status,
+            body: serde_json::to_vec(&body).unwrap(),
+        });
+    }
+
+    fn requests(&self) -> Vec<Value> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Transport for ScriptedTransport {
+    fn send(&self, request: HttpRequest) -> TransportFuture {
+        assert!(request.url.ends_with("/getUpdates"));
+        self.requests
+            .lock()
+            .unwrap()
+            .push(serde_json::from_slice(&request.body).unwrap());
+        let response = self.responses.lock().unwrap().pop_front();
+        Box::pin(async move {
+            match response {
+                Some(response) => Ok(response),
+                // Nothing scripted: a long poll without updates.
+                None => std::future::pending().await,
+            }
+        })
+    }
+}
+
+fn updates(ids: &[i32]) -> (u16, Value) {
+    let updates: Vec<Value> = ids
+        .iter()
+        .map(|id| {
+            json!({
+                "update_id": id,
+                "message": {
+                    "message_id": id,
+                    "date": 1660836000,
+                    "chat": { "id": 229584557, "first_name": "RanolP`22", "type": "private" },
+                    "text": "/start"
+                }
+            })
+        })
+        .collect();
+    (200, json!({ "ok": true, "result": updates }))
+}
+
+fn ids(updates: &[Update]) -> Vec<i32> {
+    updates.iter().map(|update| update.update_id).collect()
+}
+
+fn polling(transport: &ScriptedTransport) -> Polling<ScriptedTransport> {
+    Polling::new(BotApi::new("123456:ABC-DEF"), transport.clone())
+}
+
+#[tokio::test]
+async fn track_offset() {
+    let transport = ScriptedTransport::new([updates(&[100, 101]), updates(&[]), updates(&[102])]);
+    let received: Vec<Update> = polling(&transport)
+        .timeout(50)
+        .limit(2)
+        .allowed_updates(vec![UpdateKind::Message])
+        .take(3)
+        .collect()
+        .await;
+    assert_eq!(vec![100, 101, 102], ids(&received));
+    assert_eq!(
+        vec![
+            json!({ "limit": 2, "timeout": 50, "allowed_updates": ["message"] }),
+            json!({ "offset": 102, "limit": 2, "timeout": 50, "allowed_updates": ["message"] }),
+            json!({ "offset": 102, "limit": 2, "timeout": 50, "allowed_updates": ["message"] }),
+        ],
+        transport.requests()
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn back_off_on_errors() {
+    let transport = ScriptedTransport::new([
+        (502, json!("Bad Gateway")),
+        (
+            409,
+            json!({
+                "ok": false,
+                "error_code": 409,
+                "description": "Conflict: terminated by other getUpdates request; make sure that only one bot instance is running"
+            }),
+        ),
+        (
+            429,
+            json!({
+                "ok": false,
+                "error_code": 429,
+                "description": "Too Many Requests: retry after 10",
+                "parameters": { "retry_after": 10 }
+            }),
+        ),
+        (
+            500,
+            json!({ "ok": false, "error_code": 500, "description": "Internal Server Error" }),
+        ),
+        updates(&[7]),
+    ]);
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let mut stream = polling(&transport)
+        .backoff(Duration::from_secs(1), Duration::from_secs(60))
+        .on_error({
+            let errors = errors.clone();
+            move |error| errors.lock().unwrap().push(error.to_string())
+        });
+
+    let start = Instant::now();
+    assert_eq!(7, stream.next().await.unwrap().update_id);
+    // 1s, then 2s after the conflict, 10s as asked by the Bot API, then 4s.
+    assert_eq!(Duration::from_secs(17), start.elapsed());
+    assert_eq!(5, transport.requests().len());
+
+    let errors = errors.lock().unwrap();
+    assert_eq!(4, errors.len());
+    assert_eq!("unexpected HTTP status 502", errors[0]);
+    assert_eq!(
+        Error::Api(ApiError::Conflict {
+            description: "Conflict: terminated by other getUpdates request; make sure that only one bot instance is running".to_string()
+        })
+        .to_string(),
+        errors[1]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn cap_backoff() {
+    let transport = ScriptedTransport::new(
+        std::iter::repeat((502, json!("Bad Gateway")))
+            .take(4)
+            .chain([updates(&[1])]),
+    );
+    let mut stream = polling(&transport).backoff(Duration::from_secs(2), Duration::from_secs(5));
+    let start = Instant::now();
+    assert_eq!(1, stream.next().await.unwrap().update_id);
+    // 2s, 4s, then capped at 5s twice.
+    assert_eq!(Duration::from_secs(16), start.elapsed());
+}
+
+#[tokio::test]
+async fn shutdown_acknowledges_processed_updates() {
+    let transport = ScriptedTransport::new([updates(&[100, 101, 102])]);
+    let mut stream = polling(&transport);
+    let handle = stream.shutdown_handle();
+
+    let mut processed = Vec::new();
+    while let Some(update) = stream.next().await {
+        processed.push(update.update_id);
+        if update.update_id == 101 {
+            handle.shutdown();
+        }
+    }
+    assert_eq!(vec![100, 101], processed);
+
+    transport.push(200, updates(&[102]).1);
+    stream.shutdown().await.unwrap();
+    // Update 102 was never processed, so it is left to be received again.
+    assert_eq!(
+        json!({ "offset": 102, "limit": 1, "timeout": 0 }),
+        transport.requests()[1]
+    );
+}
+
+#[tokio::test]
+async fn shutdown_while_polling() {
+    let transport = ScriptedTransport::new([updates(&[100])]);
+    let mut stream = polling(&transport);
+    let handle = stream.shutdown_handle();
+
+    assert_eq!(100, stream.next().await.unwrap().update_id);
+    tokio::spawn(async move {
+        tokio::task::yield_now().await;
+        handle.shutdown();
+    });
+    // The next long poll never returns, so the shutdown has to interrupt it.
+    assert_eq!(None, stream.next().await);
+    assert_eq!(2, transport.requests().len());
+
+    transport.push(200, updates(&[]).1);
+    stream.shutdown().await.unwrap();
+    assert_eq!(
+        json!({ "offset": 101, "limit": 1, "timeout": 0 }),
+        transport.requests()[2]
+    );
+}
+
+#[tokio::test]
+async fn shutdown_without_updates() {
+    let transport = ScriptedTransport::new([]);
+    let stream = polling(&transport);
+    stream.shutdown_handle().shutdown();
+    stream.shutdown().await.unwrap();
+    assert!(transport.requests().is_empty());
+}
+
+#[test]
+fn debug_hides_token() {
+    let transport = ScriptedTransport::new([]);
+    assert!(!format!("{:?}", polling(&transport)).contains("ABC-DEF"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn end_on_unauthorized() {
+    let transport = ScriptedTransport::new([(
+        401,
+        json!({ "ok": false, "error_code": 401, "description": "Unauthorized" }),
+    )]);
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let mut stream = polling(&transport).on_error({
+        let errors = errors.clone();
+        move |error| errors.lock().unwrap().push(error.to_string())
+    });
+    assert_eq!(None, stream.next().await);
+    assert_eq!(None, stream.next().await);
+    assert_eq!(1, transport.requests().len());
+    assert_eq!(
+        vec!["Bot API error: Unauthorized (error code 401)".to_string()],
+        *errors.lock().unwrap()
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn saturate_backoff() {
+    let transport = ScriptedTransport::new([(502, json!("Bad Gateway")), updates(&[1])]);
+    let mut stream = polling(&transport).backoff(Duration::from_secs(1), Duration::MAX);
+    let start = Instant::now();
+    assert_eq!(1, stream.next().await.unwrap().update_id);
+    assert_eq!(Duration::from_secs(1), start.elapsed());
+
+    let transport = ScriptedTransport::new([(502, json!("Bad Gateway"))]);
+    let mut stream = polling(&transport).backoff(Duration::MAX, Duration::MAX);
+    let next = tokio::time::timeout(Duration::from_secs(3600), stream.next()).await;
+    assert!(next.is_err());
+}
@@ -0,0 +1,186 @@
+use apid_telegram_bot::{
+    calls::{EditMessageLiveLocation, MessageTarget, SendChatAction, SendPoll, SendVenue},
+    types::{ChatAction, ChatId, Message, MessageContent, ParseMode, PollKind},
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn serialize_send_venue() {
+    let call = SendVenue {
+        chat_id: ChatId::Int(229584557),
+        latitude: 37.5665,
+        longitude: 126.978,
+        title: "Seoul City Hall".to_string(),
+        address: "110 Sejong-daero".to_string(),
+        foursquare_id: None,
+        foursquare_type: None,
+        google_place_id: Some("ChIJzWXFYYuifDUR64Pq5LTtioU".to_string()),
+        google_place_type: None,
+        disable_notification: false,
+        protect_content: false,
+        reply_to_message_id: None,
+        allow_sending_without_reply: false,
+        reply_markup: None,
+    };
+    assert_eq!(
+        json!({
+            "chat_id": 229584557,
+            "latitude": 37.5665,
+            "longitude": 126.978,
+            "title": "Seoul City Hall",
+            "address": "110 Sejong-daero",
+            "google_place_id": "ChIJzWXFYYuifDUR64Pq5LTtioU"
+        }),
+        serde_json::to_value(&call).unwrap()
+    );
+}
+
+#[test]
+fn serialize_edit_message_live_location() {
+    let call = EditMessageLiveLocation {
+        target: MessageTarget::Inline {
+            inline_message_id: "AQAAAGs3AAB".to_string(),
+        },
+        latitude: 37.5665,
+        longitude: 126.978,
+        horizontal_accuracy: Some(10.5),
+        heading: Some(90),
+        proximity_alert_radius: None,
+        reply_markup: None,
+    };
+    assert_eq!(
+        json!({
+            "inline_message_id": "AQAAAGs3AAB",
+            "latitude": 37.5665,
+            "longitude": 126.978,
+            "horizontal_accuracy": 10.5,
+            "heading": 90
+        }),
+        serde_json::to_value(&call).unwrap()
+    );
+}
+
+#[test]
+fn serialize_send_poll() {
+    let call = SendPoll {
+        chat_id: ChatId::String("@apid".to_string()),
+        question: "2 + 2 = ?".to_string(),
+        options: vec!["3".to_string(), "4".to_string()],
+        is_anonymous: Some(false),
+        kind: PollKind::Quiz {
+            correct_option_id: Some(1),
+            explanation: Some("*Basic* arithmetic".to_string()),
+            explanation_entities: None,
+        },
+        allows_multiple_answers: false,
+        explanation_parse_mode: Some(ParseMode::MarkdownV2),
+        open_period: Some(60),
+        close_date: None,
+        is_closed: false,
+        disable_notification: false,
+        protect_content: false,
+        reply_to_message_id: None,
+        allow_sending_without_reply: false,
+        reply_markup: None,
+    };
+    assert_eq!(
+        json!({
+            "chat_id": "@apid",
+            "question": "2 + 2 = ?",
+            "options": ["3", "4"],
+            "is_anonymous": false,
+            "type": "quiz",
+            "correct_option_id": 1,
+            "explanation": "*Basic* arithmetic",
+            "explanation_parse_mode": "MarkdownV2",
+            "open_period": 60
+        }),
+        serde_json::to_value(&call).unwrap()
+    );
+
+    let call = SendPoll {
+        chat_id: ChatId::String("@apid".to_string()),
+        question: "Lunch?".to_string(),
+        options: vec!["Bibimbap".to_string(), "Naengmyeon".to_string()],
+        is_anonymous: None,
+        kind: PollKind::Regular,
+        allows_multiple_answers: true,
+        explanation_parse_mode: None,
+        open_period: None,
+        close_date: None,
+        is_closed: false,
+        disable_notification: false,
+        protect_content: false,
+        reply_to_message_id: None,
+        allow_sending_without_reply: false,
+        reply_markup: None,
+    };
+    assert_eq!(
+        json!({
+            "chat_id": "@apid",
+            "question": "Lunch?",
+            "options": ["Bibimbap", "Naengmyeon"],
+            "type": "regular",
+            "allows_multiple_answers": true
+        }),
+        serde_json::to_value(&call).unwrap()
+    );
+}
+
+#[test]
+fn serialize_send_chat_action() {
+    let call = SendChatAction {
+        chat_id: ChatId::Int(229584557),
+        action: ChatAction::UploadVideoNote,
+    };
+    assert_eq!(
+        json!({ "chat_id": 229584557, "action": "upload_video_note" }),
+        serde_json::to_value(&call).unwrap()
+    );
+}
+
+#[test]
+fn deserialize_venue_and_proximity_alert() {
+    let user = json!({ "id": 229584557, "is_bot": false, "first_name": "RanolP`22" });
+    let chat = json!({ "id": 229584557, "first_name": "RanolP`22", "type": "private" });
+    let location = json!({ "latitude": 37.5665, "longitude": 126.978 });
+
+    let message: Message = serde_json::from_value(json!({
+        "message_id": 1300,
+        "chat": chat,
+        "date": 1660836000,
+        "venue": {
+            "location": location,
+            "title": "Seoul City Hall",
+            "address": "110 Sejong-daero"
+        },
+        "location": location
+    }))
+    .unwrap();
+    match message.content {
+        MessageContent::Venue { venue, .. } => {
+            assert_eq!("Seoul City Hall", venue.title);
+            assert_eq!(None, venue.foursquare_id);
+        }
+        content => panic!("expected a venue, got {:?}", content),
+    }
+
+    let message: Message = serde_json::from_value(json!({
+        "message_id": 1301,
+        "chat": chat,
+        "date": 1660836000,
+        "proximity_alert_triggered": {
+            "traveler": user,
+            "watcher": user,
+            "distance": 42
+        }
+    }))
+    .unwrap();
+    match message.content {
+        MessageContent::ProximityAlertTriggered {
+            proximity_alert_triggered,
+        } => assert_eq!(42, proximity_alert_triggered.distance),
+        content => panic!("expected a proximity alert, got {:?}", content),
+    }
+}
@@ -0,0 +1,185 @@
+use apid::Api;
+use apid_telegram_bot::{
+    calls::{SendAudio, SendDocument, SendPhoto, SendVideoNote},
+    types::{Caption, ChatId, InputFile, InputFileUpload, ParseMode},
+    BotApi,
+};
+use pretty_assertions::assert_eq;
+
+fn body(request: apid::HttpRequest) -> String {
+    String::from_utf8(request.body).unwrap()
+}
+
+#[test]
+fn send_photo_by_file_id() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let request = api
+        .request(&SendPhoto {
+            chat_id: ChatId::Int(229584557),
+            photo: InputFile::FileId("AgACAgUAAxkBAAIBY2".to_string()),
+            caption: Some(Caption {
+                text: "*A cat*".to_string(),
+                caption_entities: vec![],
+            }),
+            parse_mode: Some(ParseMode::MarkdownV2),
+            disable_notification: false,
+            protect_content: false,
+            reply_to_message_id: Some(1200),
+            allow_sending_without_reply: true,
+            reply_markup: None,
+        })
+        .unwrap();
+    assert_eq!(
+        "https://api.telegram.org/bot123456:ABC-DEF/sendPhoto",
+        request.url
+    );
+    assert_eq!(
+        concat!(
+            r#"{"chat_id":229584557,"photo":"AgACAgUAAxkBAAIBY2","caption":"*A cat*","#,
+            r#""parse_mode":"MarkdownV2","reply_to_message_id":1200,"allow_sending_without_reply":true}"#,
+        ),
+        body(request)
+    );
+}
+
+#[test]
+fn send_video_note_by_url() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let request = api
+        .request(&SendVideoNote {
+            chat_id: ChatId::String("@apid".to_string()),
+            video_note: InputFile::Url("https://example.com/note.mp4".to_string()),
+            duration: Some(12),
+            length: Some(240),
+            thumb: None,
+            disable_notification: true,
+            protect_content: false,
+            reply_to_message_id: None,
+            allow_sending_without_reply: false,
+            reply_markup: None,
+        })
+        .unwrap();
+    assert_eq!(
+        r#"{"chat_id":"@apid","video_note":"https://example.com/note.mp4","duration":12,"length":240,"disable_notification":true}"#,
+        body(request)
+    );
+}
+
+#[test]
+fn send_audio_with_uploaded_thumb() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let request = api
+        .request(&SendAudio {
+            chat_id: ChatId::Int(229584557),
+            audio: InputFile::upload("song", "song.mp3", b"ID3".to_vec()),
+            caption: None,
+            parse_mode: None,
+            duration: Some(180),
+            performer: Some("RanolP".to_string()),
+            title: None,
+            thumb: Some(InputFileUpload::new("cover", "cover.jpg", b"JPEG".to_vec())),
+            disable_notification: false,
+            protect_content: false,
+            reply_to_message_id: None,
+            allow_sending_without_reply: false,
+            reply_markup: None,
+        })
+        .unwrap();
+    assert_eq!(
+        concat!(
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"audio\"\r\n",
+            "\r\n",
+            "attach://song\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"chat_id\"\r\n",
+            "\r\n",
+            "229584557\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"duration\"\r\n",
+            "\r\n",
+            "180\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"performer\"\r\n",
+            "\r\n",
+            "RanolP\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"thumb\"\r\n",
+            "\r\n",
+            "attach://cover\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"song\"; filename=\"song.mp3\"\r\n",
+            "Content-Type: application/octet-stream\r\n",
+            "\r\n",
+            "ID3\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"cover\"; filename=\"cover.jpg\"\r\n",
+            "Content-Type: application/octet-stream\r\n",
+            "\r\n",
+            "JPEG\r\n",
+            "--apid-boundary-0--\r\n",
+        ),
+        body(request)
+    );
+}
+
+#[test]
+fn send_document_with_caption_entities() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let request = api
+        .request(&SendDocument {
+            chat_id: ChatId::Int(229584557),
+            document: InputFile::upload("report", "report.pdf", b"%PDF".to_vec()),
+            thumb: None,
+            caption: Some(Caption {
+                text: "Report".to_string(),
+                caption_entities: serde_json::from_str(
+                    r#"[{"type":"italic","offset":0,"length":6}]"#,
+                )
+                .unwrap(),
+            }),
+            parse_mode: None,
+            disable_content_type_detection: true,
+            disable_notification: false,
+            protect_content: true,
+            reply_to_message_id: None,
+            allow_sending_without_reply: false,
+            reply_markup: None,
+        })
+        .unwrap();
+    assert_eq!(
+        concat!(
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"caption\"\r\n",
+            "\r\n",
+            "Report\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"caption_entities\"\r\n",
+            "\r\n",
+            "[{\"length\":6,\"offset\":0,\"type\":\"italic\"}]\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"chat_id\"\r\n",
+            "\r\n",
+            "229584557\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"disable_content_type_detection\"\r\n",
+            "\r\n",
+            "true\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"document\"\r\n",
+            "\r\n",
+            "attach://report\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"protect_content\"\r\n",
+            "\r\n",
+            "true\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"report\"; filename=\"report.pdf\"\r\n",
+            "Content-Type: application/octet-stream\r\n",
+            "\r\n",
+            "%PDF\r\n",
+            "--apid-boundary-0--\r\n",
+        ),
+        body(request)
+    );
+}
@@ -0,0 +1,145 @@
+use apid_telegram_bot::{
+    calls::SendMessage,
+    types::{ChatId, ForceReply, MessageEntity, MessageEntityKind, ParseMode, True},
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn serialize_minimal_send_message() {
+    let call = SendMessage {
+        chat_id: ChatId::String("@apid_channel".to_string()),
+        text: "Hello, world!".to_string(),
+        parse_mode: None,
+        entities: vec![],
+        disable_web_page_preview: false,
+        disable_notification: false,
+        protect_content: false,
+        reply_to_message_id: None,
+        allow_sending_without_reply: false,
+        reply_markup: None,
+    };
+    assert_eq!(
+        json!({
+            "chat_id": "@apid_channel",
+            "text": "Hello, world!",
+        }),
+        serde_json::to_value(&call).unwrap(),
+    );
+}
+
+#[test]
+fn serialize_send_message_with_parse_mode() {
+    for (parse_mode, expected) in [
+        (ParseMode::MarkdownV2, "MarkdownV2"),
+        (ParseMode::HTML, "HTML"),
+        (ParseMode::Markdown, "Markdown"),
+    ] {
+        let call = SendMessage {
+            chat_id: ChatId::Int(229584557),
+            text: "*bold*".to_string(),
+            parse_mode: Some(parse_mode),
+            entities: vec![],
+            disable_web_page_preview: true,
+            disable_notification: true,
+            protect_content: true,
+            reply_to_message_id: Some(42),
+            allow_sending_without_reply: true,
+            reply_markup: None,
+        };
+        assert_eq!(
+            json!({
+                "chat_id": 229584557,
+                "text": "*bold*",
+                "parse_mode": expected,
+                "disable_web_page_preview": true,
+                "disable_notification": true,
+                "protect_content": true,
+                "reply_to_message_id": 42,
+                "allow_sending_without_reply": true,
+            }),
+            serde_json::to_value(&call).unwrap(),
+        );
+    }
+}
+
+#[test]
+fn serialize_send_message_with_entities() {
+    let call = SendMessage {
+        chat_id: ChatId::Int(229584557),
+        text: "Visit apid".to_string(),
+        parse_mode: None,
+        entities: vec![
+            MessageEntity {
+                kind: MessageEntityKind::Bold,
+                offset: 0,
+                length: 5,
+            },
+            MessageEntity {
+                kind: MessageEntityKind::TextLink {
+                    url: "https://github.com/bot-any/apid".to_string(),
+                },
+                offset: 6,
+                length: 4,
+            },
+        ],
+        disable_web_page_preview: false,
+        disable_notification: false,
+        protect_content: false,
+        reply_to_message_id: None,
+        allow_sending_without_reply: false,
+        reply_markup: None,
+    };
+    assert_eq!(
+        json!({
+            "chat_id": 229584557,
+            "text": "Visit apid",
+            "entities": [
+                { "type": "bold", "offset": 0, "length": 5 },
+                {
+                    "type": "text_link",
+                    "url": "https://github.com/bot-any/apid",
+                    "offset": 6,
+                    "length": 4,
+                },
+            ],
+        }),
+        serde_json::to_value(&call).unwrap(),
+    );
+}
+
+#[test]
+fn serialize_send_message_with_reply_markup() {
+    let call = SendMessage {
+        chat_id: ChatId::Int(229584557),
+        text: "What's your name?".to_string(),
+        parse_mode: None,
+        entities: vec![],
+        disable_web_page_preview: false,
+        disable_notification: false,
+        protect_content: false,
+        reply_to_message_id: Some(42),
+        allow_sending_without_reply: false,
+        reply_markup: Some(
+            ForceReply {
+                force_reply: True,
+                input_field_placeholder: Some("Name".to_string()),
+                selective: true,
+            }
+            .into(),
+        ),
+    };
+    assert_eq!(
+        json!({
+            "chat_id": 229584557,
+            "text": "What's your name?",
+            "reply_to_message_id": 42,
+            "reply_markup": {
+                "force_reply": true,
+                "input_field_placeholder": "Name",
+                "selective": true,
+            },
+        }),
+        serde_json::to_value(&call).unwrap(),
+    );
+}
@@ -0,0 +1,144 @@
+use apid::Api;
+use apid_telegram_bot::{
+    calls::{CreateNewStickerSet, GetStickerSet, StickerFile},
+    types::{
+        ApiResponse, InputFile, MaskPosition, MaskPositionPoint, PhotoSize, Sticker, StickerFormat,
+        StickerKind, StickerSet,
+    },
+    BotApi,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn deserialize_sticker_set() {
+    let src = r#"
+        {
+            "ok": true,
+            "result": {
+                "name": "apid_by_apid_bot",
+                "title": "apid",
+                "is_animated": false,
+                "is_video": true,
+                "sticker_type": "regular",
+                "contains_masks": false,
+                "stickers": [
+                    {
+                        "width": 512,
+                        "height": 512,
+                        "emoji": "😀",
+                        "set_name": "apid_by_apid_bot",
+                        "is_animated": false,
+                        "is_video": true,
+                        "type": "regular",
+                        "thumb": {
+                            "file_id": "AAMCAQADFQABYyfSnK2o1s6hzR4ZrG2iy5XrLdIAAgECAAJq4UFFu7lxgsHJTAEBAAdtAAMpBA",
+                            "file_unique_id": "AQADAQIAAmrhQUVy",
+                            "file_size": 5140,
+                            "width": 128,
+                            "height": 128
+                        },
+                        "file_id": "CAACAgEAAxUAAWMn0pytqNbOoc0eGaxtosuV6y3SAAIBAgACauFBRbu5cYLByUwBKQQ",
+                        "file_unique_id": "AgADAQIAAmrhQUU",
+                        "file_size": 23140
+                    }
+                ]
+            }
+        }
+    "#;
+    let response: ApiResponse<<GetStickerSet as apid::Call>::Response> =
+        serde_json::from_str(src).unwrap();
+    let sticker_set = response.into_result().unwrap();
+    assert_eq!(
+        StickerSet {
+            name: "apid_by_apid_bot".to_string(),
+            title: "apid".to_string(),
+            sticker_kind: StickerKind::Regular,
+            is_animated: false,
+            is_video: true,
+            stickers: vec![Sticker {
+                file_id: "CAACAgEAAxUAAWMn0pytqNbOoc0eGaxtosuV6y3SAAIBAgACauFBRbu5cYLByUwBKQQ"
+                    .to_string(),
+                file_unique_id: "AgADAQIAAmrhQUU".to_string(),
+                kind: StickerKind::Regular,
+                width: 512,
+                height: 512,
+                is_animated: false,
+                is_video: true,
+                thumb: Some(PhotoSize {
+                    file_id:
+                        "AAMCAQADFQABYyfSnK2o1s6hzR4ZrG2iy5XrLdIAAgECAAJq4UFFu7lxgsHJTAEBAAdtAAMpBA"
+                            .to_string(),
+                    file_unique_id: "AQADAQIAAmrhQUVy".to_string(),
+                    width: 128,
+                    height: 128,
+                    file_size: Some(5140),
+                }),
+                emoji: Some("😀".to_string()),
+                set_name: Some("apid_by_apid_bot".to_string()),
+                premium_animation: None,
+                mask_position: None,
+                custom_emoji_id: None,
+                file_size: Some(23140),
+            }],
+            thumb: None,
+        },
+        sticker_set,
+    );
+    assert_eq!(StickerFormat::Video, sticker_set.format());
+    assert_eq!(StickerFormat::Video, sticker_set.stickers[0].format());
+}
+
+#[test]
+fn serialize_create_new_sticker_set() {
+    let call = CreateNewStickerSet {
+        user_id: 229584557,
+        name: "masks_by_apid_bot".to_string(),
+        title: "Masks".to_string(),
+        sticker: StickerFile::PngSticker(InputFile::FileId("AgADAQIAAmrhQUU".to_string())),
+        sticker_kind: Some(StickerKind::Mask),
+        emojis: "😷".to_string(),
+        mask_position: Some(MaskPosition {
+            point: MaskPositionPoint::Eyes,
+            x_shift: 0.0,
+            y_shift: -0.5,
+            scale: 1.5,
+        }),
+    };
+    assert_eq!(
+        json!({
+            "user_id": 229584557,
+            "name": "masks_by_apid_bot",
+            "title": "Masks",
+            "png_sticker": "AgADAQIAAmrhQUU",
+            "sticker_type": "mask",
+            "emojis": "😷",
+            "mask_position": {
+                "point": "eyes",
+                "x_shift": 0.0,
+                "y_shift": -0.5,
+                "scale": 1.5,
+            },
+        }),
+        serde_json::to_value(&call).unwrap(),
+    );
+}
+
+#[test]
+fn upload_animated_sticker() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let request = api
+        .request(&CreateNewStickerSet {
+            user_id: 229584557,
+            name: "animals_by_apid_bot".to_string(),
+            title: "Animals".to_string(),
+            sticker: StickerFile::TgsSticker(InputFile::upload("cat", "cat.tgs", b"TGS".to_vec())),
+            sticker_kind: None,
+            emojis: "🐱".to_string(),
+            mask_position: None,
+        })
+        .unwrap();
+    let body = String::from_utf8(request.body).unwrap();
+    assert!(body.contains("name=\"tgs_sticker\"\r\n\r\nattach://cat\r\n"));
+    assert!(body.contains("name=\"cat\"; filename=\"cat.tgs\""));
+}
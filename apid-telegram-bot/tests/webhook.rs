@@ -0,0 +1,95 @@
+use apid::{Api, HttpResponse};
+use apid_telegram_bot::{
+    calls::{DeleteWebhook, GetWebhookInfo, SetWebhook},
+    types::{InputFileUpload, UpdateKind},
+    BotApi,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn set_webhook_with_certificate() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let request = api
+        .request(&SetWebhook {
+            url: "https://bot.example.com/telegram".to_string(),
+            certificate: Some(InputFileUpload::new("cert", "cert.pem", b"PEM".to_vec())),
+            ip_address: None,
+            max_connections: Some(10),
+            allowed_updates: Some(vec![UpdateKind::Message, UpdateKind::CallbackQuery]),
+            drop_pending_updates: true,
+            secret_token: Some("s3cr3t_token".to_string()),
+        })
+        .unwrap();
+    assert_eq!(
+        concat!(
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"allowed_updates\"\r\n",
+            "\r\n",
+            "[\"message\",\"callback_query\"]\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"certificate\"\r\n",
+            "\r\n",
+            "attach://cert\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"drop_pending_updates\"\r\n",
+            "\r\n",
+            "true\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"max_connections\"\r\n",
+            "\r\n",
+            "10\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"secret_token\"\r\n",
+            "\r\n",
+            "s3cr3t_token\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"url\"\r\n",
+            "\r\n",
+            "https://bot.example.com/telegram\r\n",
+            "--apid-boundary-0\r\n",
+            "Content-Disposition: form-data; name=\"cert\"; filename=\"cert.pem\"\r\n",
+            "Content-Type: application/octet-stream\r\n",
+            "\r\n",
+            "PEM\r\n",
+            "--apid-boundary-0--\r\n",
+        ),
+        String::from_utf8(request.body).unwrap()
+    );
+}
+
+#[test]
+fn delete_webhook() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let request = api
+        .request(&DeleteWebhook {
+            drop_pending_updates: true,
+        })
+        .unwrap();
+    assert_eq!(
+        "https://api.telegram.org/bot123456:ABC-DEF/deleteWebhook",
+        request.url
+    );
+    assert_eq!(
+        r#"{"drop_pending_updates":true}"#,
+        String::from_utf8(request.body).unwrap()
+    );
+}
+
+#[test]
+fn decode_webhook_info() {
+    let api = BotApi::new("123456:ABC-DEF");
+    let info = api
+        .decode::<GetWebhookInfo>(HttpResponse {
+            status: 200,
+            body: br#"{"ok":true,"result":{"url":"https://bot.example.com/telegram","has_custom_certificate":true,"pending_update_count":3,"max_connections":10,"ip_address":"203.0.113.7","allowed_updates":["message","my_chat_member"]}}"#.to_vec(),
+        })
+        .unwrap();
+    assert_eq!(3, info.pending_update_count);
+    assert_eq!(
+        Some(vec![
+            UpdateKind::Message,
+            UpdateKind::PrivateChatMemberUpdated
+        ]),
+        info.allowed_updates
+    );
+}
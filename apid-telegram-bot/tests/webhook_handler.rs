@@ -0,0 +1,167 @@
+#![cfg(feature = "webhook")]
+
+use apid_telegram_bot::{
+    types::{Update, UpdateEvent},
+    WebhookError, WebhookHandler, SECRET_TOKEN_HEADER,
+};
+use pretty_assertions::assert_eq;
+
+const BODY: &[u8] = br#"{"update_id":721490500,"message":{"message_id":42,"date":1660836000,"chat":{"id":229584557,"first_name":"RanolP`22","type":"private"},"from":{"id":229584557,"is_bot":false,"first_name":"RanolP`22"},"text":"/start"}}"#;
+
+fn assert_message(update: Update) {
+    assert_eq!(721490500, update.update_id);
+    match update.event {
+        Some(UpdateEvent::Message { message }) => assert_eq!(42, message.message_id),
+        event => panic!("expected a message, got {:?}", event),
+    }
+}
+
+#[test]
+fn handle_update_with_secret_token() {
+    let handler = WebhookHandler::new("s3cr3t_token");
+    let update = handler
+        .handle(
+            [
+                ("content-type", b"application/json".as_slice()),
+                (
+                    "x-telegram-bot-api-secret-token",
+                    b"s3cr3t_token".as_slice(),
+                ),
+            ],
+            BODY,
+        )
+        .unwrap();
+    assert_message(update);
+}
+
+#[test]
+fn reject_wrong_secret_token() {
+    let handler = WebhookHandler::new("s3cr3t_token");
+    for token in [b"s3cr3t_tokem".as_slice(), b"s3cr3t", b""] {
+        let error = handler
+            .handle([(SECRET_TOKEN_HEADER, token)], BODY)
+            .unwrap_err();
+        assert!(matches!(error, WebhookError::InvalidSecretToken));
+        assert_eq!(401, error.status_code());
+    }
+
+    let error = handler.handle([], BODY).unwrap_err();
+    assert!(matches!(error, WebhookError::MissingSecretToken));
+    assert_eq!(401, error.status_code());
+}
+
+#[test]
+fn handle_update_without_secret_token() {
+    let handler = WebhookHandler::without_secret_token();
+    assert_message(
+        handler
+            .handle([(SECRET_TOKEN_HEADER, b"anything".as_slice())], BODY)
+            .unwrap(),
+    );
+    assert_message(handler.handle([], BODY).unwrap());
+}
+
+#[test]
+fn reject_malformed_body() {
+    let handler = WebhookHandler::without_secret_token();
+    let error = handler.handle([], b"{\"update\":").unwrap_err();
+    assert!(matches!(error, WebhookError::Json(_)));
+    assert_eq!(400, error.status_code());
+}
+
+#[test]
+fn debug_hides_secret_token() {
+    let debug = format!("{:?}", WebhookHandler::new("s3cr3t_token"));
+    assert!(!debug.contains("s3cr3t_token"));
+}
+
+/// A body failing the test if it is read.
+#[cfg(feature = "hyper")]
+struct UnreadBody;
+
+#[cfg(feature = "hyper")]
+impl http_body::Body for UnreadBody {
+    type Data = &'static [u8];
+    type Error = std::convert::Infallible;
+
+    fn poll_frame(
+        self: std::pin::Pin<&mut Self>,
+        _: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Result<http_body::Frame<Self::Data>, Self::Error>>> {
+        panic!("the body was read before the secret token was checked")
+    }
+}
+
+#[cfg(feature = "hyper")]
+#[tokio::test]
+async fn check_secret_token_before_reading_body() {
+    let handler = WebhookHandler::new("s3cr3t_token");
+    let request = http::Request::post("/telegram")
+        .header(SECRET_TOKEN_HEADER, "wrong")
+        .body(UnreadBody)
+        .unwrap();
+    let error = handler.handle_request(request).await.unwrap_err();
+    assert!(matches!(error, WebhookError::InvalidSecretToken));
+}
+
+#[cfg(feature = "hyper")]
+#[tokio::test]
+async fn reject_oversized_body() {
+    use apid_telegram_bot::BODY_LIMIT;
+
+    let handler = WebhookHandler::new("s3cr3t_token");
+    let request = http::Request::post("/telegram")
+        .header(SECRET_TOKEN_HEADER, "s3cr3t_token")
+        .body(http_body_util::Full::new(std::io::Cursor::new(vec![
+            b' ';
+            BODY_LIMIT
+                + 1
+        ])))
+        .unwrap();
+    let error = handler.handle_request(request).await.unwrap_err();
+    assert!(matches!(error, WebhookError::Body(_)));
+    assert_eq!(400, error.status_code());
+}
+
+#[cfg(feature = "hyper")]
+#[tokio::test]
+async fn handle_http_request() {
+    let handler = WebhookHandler::new("s3cr3t_token");
+    let request = http::Request::post("/telegram")
+        .header(SECRET_TOKEN_HEADER, "s3cr3t_token")
+        .body(http_body_util::Full::new(BODY))
+        .unwrap();
+    assert_message(handler.handle_request(request).await.unwrap());
+}
+
+#[cfg(feature = "axum")]
+#[tokio::test]
+async fn extract_update_with_axum() {
+    use apid_telegram_bot::WebhookUpdate;
+    use axum::{
+        body::Body,
+        extract::FromRequest,
+        http::{Request, StatusCode},
+        response::IntoResponse,
+    };
+
+    let handler = WebhookHandler::new("s3cr3t_token");
+
+    let request = Request::post("/telegram")
+        .header(SECRET_TOKEN_HEADER, "s3cr3t_token")
+        .body(Body::from(BODY))
+        .unwrap();
+    let WebhookUpdate(update) = WebhookUpdate::from_request(request, &handler)
+        .await
+        .unwrap();
+    assert_message(update);
+
+    let request = Request::post("/telegram")
+        .header(SECRET_TOKEN_HEADER, "wrong")
+        .body(Body::from(BODY))
+        .unwrap();
+    let rejection = WebhookUpdate::from_request(request, &handler)
+        .await
+        .unwrap_err();
+    assert_eq!(StatusCode::UNAUTHORIZED, rejection.into_response().status());
+}
@@ -0,0 +1,35 @@
+use serde::Serialize;
+
+use crate::{multipart, Call, HttpRequest, HttpResponse};
+
+/// This object represents an API server which [`Call`]s could be sent to.
+pub trait Api {
+    /// The error returned when a call fails.
+    type Error: From<serde_json::Error>;
+
+    /// Builds the URL of the API method named `name`.
+    fn url(&self, name: &str) -> String;
+
+    /// Builds an HTTP request for the call.
+    ///
+    /// The call is sent as a JSON body,
+    /// or as a `multipart/form-data` body if it has any [`Attachment`](crate::Attachment).
+    fn request<C: Call + Serialize>(&self, call: &C) -> Result<HttpRequest, serde_json::Error> {
+        let attachments = call.attachments();
+        let (content_type, body) = if attachments.is_empty() {
+            ("application/json".to_string(), serde_json::to_vec(call)?)
+        } else {
+            let (boundary, body) = multipart::encode(call, &attachments)?;
+            (format!("multipart/form-data; boundary={}", boundary), body)
+        };
+        Ok(HttpRequest {
+            method: C::HTTP_METHOD,
+            url: self.url(C::NAME),
+            headers: vec![("Content-Type".to_string(), content_type)],
+            body,
+        })
+    }
+
+    /// Decodes the HTTP response of the call.
+    fn decode<C: Call>(&self, response: HttpResponse) -> Result<C::Response, Self::Error>;
+}
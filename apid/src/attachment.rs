@@ -0,0 +1,12 @@
+/// A file uploaded alongside a [`Call`](crate::Call) as a part of `multipart/form-data` body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Attachment<'a> {
+    /// The name of the form field holding the file
+    pub name: &'a str,
+
+    /// The name of the file
+    pub file_name: &'a str,
+
+    /// The contents of the file
+    pub data: &'a [u8],
+}
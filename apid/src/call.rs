@@ -1,7 +1,21 @@
 use serde::de::DeserializeOwned;
 
+use crate::{Attachment, HttpMethod};
+
 /// This object could be used to call an API.
 pub trait Call {
     /// The return type of the API on success.
     type Response: DeserializeOwned;
+
+    /// The name of the API method, i.e. its path relative to the base URL of the API.
+    const NAME: &'static str;
+
+    /// The HTTP method used to call the API.
+    const HTTP_METHOD: HttpMethod = HttpMethod::Post;
+
+    /// The files uploaded alongside the call.
+    /// If there is any, the call is sent as `multipart/form-data` instead of JSON.
+    fn attachments(&self) -> Vec<Attachment<'_>> {
+        Vec::new()
+    }
 }
@@ -0,0 +1,30 @@
+use crate::HttpMethod;
+
+/// A transport-agnostic description of an HTTP request calling an API.
+///
+/// It could be sent by any HTTP library,
+/// and the [`HttpResponse`] received back could be decoded with [`Api::decode`](crate::Api::decode).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    /// The HTTP method of the request
+    pub method: HttpMethod,
+
+    /// The full URL the request is sent to
+    pub url: String,
+
+    /// The headers of the request, as name-value pairs
+    pub headers: Vec<(String, String)>,
+
+    /// The body of the request
+    pub body: Vec<u8>,
+}
+
+/// A transport-agnostic description of an HTTP response received from an API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    /// The status code of the response
+    pub status: u16,
+
+    /// The body of the response
+    pub body: Vec<u8>,
+}
@@ -0,0 +1,27 @@
+/// The HTTP method used to call an API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HttpMethod {
+    /// The `GET` method
+    Get,
+    /// The `POST` method
+    Post,
+    /// The `PUT` method
+    Put,
+    /// The `PATCH` method
+    Patch,
+    /// The `DELETE` method
+    Delete,
+}
+
+impl HttpMethod {
+    /// The name of the method as written in an HTTP request line.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HttpMethod::Get => "GET",
+            HttpMethod::Post => "POST",
+            HttpMethod::Put => "PUT",
+            HttpMethod::Patch => "PATCH",
+            HttpMethod::Delete => "DELETE",
+        }
+    }
+}
@@ -2,6 +2,15 @@
 
 #![deny(missing_docs)]
 
+pub use api::Api;
+pub use attachment::Attachment;
 pub use call::Call;
+pub use http::{HttpRequest, HttpResponse};
+pub use http_method::HttpMethod;
 
+mod api;
+mod attachment;
 mod call;
+mod http;
+mod http_method;
+mod multipart;
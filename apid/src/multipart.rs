@@ -0,0 +1,114 @@
+use serde::{ser::Error, Serialize};
+use serde_json::Value;
+
+use crate::Attachment;
+
+const BOUNDARY_PREFIX: &str = "apid-boundary-";
+
+/// Encodes the call as a `multipart/form-data` body, returning its boundary and bytes.
+///
+/// Every field of the call becomes a text part, strings as is and other values as JSON,
+/// followed by a part for every attachment.
+/// Fails if an attachment is named the same as a field or another attachment, which would make the form ambiguous.
+pub(crate) fn encode<C: Serialize>(
+    call: &C,
+    attachments: &[Attachment<'_>],
+) -> Result<(String, Vec<u8>), serde_json::Error> {
+    let fields = match serde_json::to_value(call)? {
+        Value::Object(fields) => fields,
+        _ => {
+            return Err(serde_json::Error::custom(
+                "multipart call must be serialized as an object",
+            ))
+        }
+    };
+    let mut texts = Vec::with_capacity(fields.len());
+    for (name, value) in fields {
+        let text = match value {
+            Value::Null => continue,
+            Value::String(text) => text,
+            value => serde_json::to_string(&value)?,
+        };
+        texts.push((name, text));
+    }
+
+    for (i, attachment) in attachments.iter().enumerate() {
+        if texts.iter().any(|(name, _)| name == attachment.name) {
+            return Err(serde_json::Error::custom(format_args!(
+                "attachment `{}` is named the same as a field",
+                attachment.name
+            )));
+        }
+        if attachments[..i]
+            .iter()
+            .any(|other| other.name == attachment.name)
+        {
+            return Err(serde_json::Error::custom(format_args!(
+                "attachment `{}` is named the same as another attachment",
+                attachment.name
+            )));
+        }
+    }
+
+    let boundary = boundary(&texts, attachments);
+    let mut body = Vec::new();
+    for (name, text) in &texts {
+        write_header(&mut body, &boundary, name, None);
+        body.extend_from_slice(text.as_bytes());
+        body.extend_from_slice(b"\r\n");
+    }
+    for attachment in attachments {
+        write_header(
+            &mut body,
+            &boundary,
+            attachment.name,
+            Some(attachment.file_name),
+        );
+        body.extend_from_slice(attachment.data);
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+    Ok((boundary, body))
+}
+
+/// Picks a boundary which doesn't appear in any part.
+fn boundary(texts: &[(String, String)], attachments: &[Attachment<'_>]) -> String {
+    (0..)
+        .map(|n| format!("{}{}", BOUNDARY_PREFIX, n))
+        .find(|boundary| {
+            let boundary = boundary.as_bytes();
+            !texts
+                .iter()
+                .any(|(_, text)| contains(text.as_bytes(), boundary))
+                && !attachments
+                    .iter()
+                    .any(|attachment| contains(attachment.data, boundary))
+        })
+        .expect("some boundary must be available")
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+fn write_header(body: &mut Vec<u8>, boundary: &str, name: &str, file_name: Option<&str>) {
+    body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+    body.extend_from_slice(
+        format!("Content-Disposition: form-data; name=\"{}\"", escape(name)).as_bytes(),
+    );
+    if let Some(file_name) = file_name {
+        body.extend_from_slice(format!("; filename=\"{}\"", escape(file_name)).as_bytes());
+        body.extend_from_slice(b"\r\nContent-Type: application/octet-stream");
+    }
+    body.extend_from_slice(b"\r\n\r\n");
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}